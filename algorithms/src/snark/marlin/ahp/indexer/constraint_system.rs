@@ -70,6 +70,18 @@ impl<F: Field> ConstraintSystem<F> {
         );
     }
 
+    /// Returns the maximum and the average number of terms in the linear combinations
+    /// of all enforced constraints, which helps locate gadgets with un-simplified combinations.
+    pub(crate) fn linear_combination_sizes(&self) -> (usize, f64) {
+        let rows = || self.a.iter().chain(&self.b).chain(&self.c);
+        let max_terms = rows().map(Vec::len).max().unwrap_or(0);
+        let total_terms = rows().map(Vec::len).sum::<usize>();
+        match self.a.len() + self.b.len() + self.c.len() {
+            0 => (0, 0.0),
+            num_linear_combinations => (max_terms, total_terms as f64 / num_linear_combinations as f64),
+        }
+    }
+
     #[inline]
     fn make_row(l: &LinearCombination<F>) -> Vec<(F, VarIndex)> {
         l.as_ref().iter().map(|(var, coeff)| (*coeff, var.get_unchecked())).collect()
@@ -90,7 +102,7 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         // function for obtaining one.
 
         let index = self.num_private_variables;
-        self.num_private_variables += 1;
+        self.num_private_variables = index.checked_add(1).ok_or(SynthesisError::TooManyVariables)?;
 
         Ok(Variable::new_unchecked(VarIndex::Private(index)))
     }
@@ -106,7 +118,7 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         // function for obtaining one.
 
         let index = self.num_public_variables;
-        self.num_public_variables += 1;
+        self.num_public_variables = index.checked_add(1).ok_or(SynthesisError::TooManyVariables)?;
 
         Ok(Variable::new_unchecked(VarIndex::Public(index)))
     }
@@ -123,7 +135,10 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         self.b.push(Self::make_row(&b(LinearCombination::zero())));
         self.c.push(Self::make_row(&c(LinearCombination::zero())));
 
-        self.num_constraints += 1;
+        // `CS::enforce` is infallible, so an overflow cannot be surfaced as a `SynthesisError`.
+        // The count matches the length of `self.a`, which cannot exceed `isize::MAX` rows.
+        self.num_constraints =
+            self.num_constraints.checked_add(1).expect("The number of constraints exceeds usize::MAX");
     }
 
     fn push_namespace<NR, N>(&mut self, _: N)
@@ -158,3 +173,45 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+
+    type F = Fr;
+
+    #[test]
+    fn test_alloc_too_many_variables() {
+        let mut cs = ConstraintSystem::<F>::new();
+
+        cs.num_private_variables = usize::MAX;
+        assert!(matches!(cs.alloc(|| "a", || Ok(F::one())), Err(SynthesisError::TooManyVariables)));
+        assert_eq!(cs.num_private_variables, usize::MAX);
+
+        cs.num_public_variables = usize::MAX;
+        assert!(matches!(cs.alloc_input(|| "b", || Ok(F::one())), Err(SynthesisError::TooManyVariables)));
+        assert_eq!(cs.num_public_variables, usize::MAX);
+    }
+
+    #[test]
+    fn test_linear_combination_sizes() {
+        let mut cs = ConstraintSystem::<F>::new();
+        assert_eq!(cs.linear_combination_sizes(), (0, 0.0));
+
+        let a = cs.alloc(|| "a", || Ok(F::one())).unwrap();
+        let b = cs.alloc(|| "b", || Ok(F::one())).unwrap();
+        let c = cs.alloc_input(|| "c", || Ok(F::one())).unwrap();
+        cs.enforce(|| "a * b = c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        cs.enforce(
+            || "(a + b + c) * 1 = (a + b + c)",
+            |lc| lc + a + b + c,
+            |lc| lc + ConstraintSystem::<F>::one(),
+            |lc| lc + a + b + c,
+        );
+
+        // The linear combinations have 1, 1, 1, 3, 1, and 3 terms.
+        assert_eq!(cs.linear_combination_sizes(), (3, 10.0 / 6.0));
+    }
+}
@@ -122,6 +122,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let constraint_time = start_timer!(|| "Generating constraints");
         let mut ics = IndexerConstraintSystem::new();
         c.generate_constraints(&mut ics)?;
+        let (max_linear_combination_size, avg_linear_combination_size) = ics.linear_combination_sizes();
         end_timer!(constraint_time);
 
         let padding_time = start_timer!(|| "Padding matrices to make them square");
@@ -150,6 +151,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             println!("Number of non-zero entries in A: {}", num_non_zero_a);
             println!("Number of non-zero entries in B: {}", num_non_zero_b);
             println!("Number of non-zero entries in C: {}", num_non_zero_c);
+            println!("Maximum number of terms in a linear combination: {}", max_linear_combination_size);
+            println!("Average number of terms in a linear combination: {:.2}", avg_linear_combination_size);
         }
 
         if num_constraints != num_variables {
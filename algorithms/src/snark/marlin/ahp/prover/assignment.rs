@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::snark::marlin::ahp::prover::ConstraintSystem;
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer};
+
+use serde::{Deserialize, Serialize};
+
+/// The witness assignment produced by synthesizing a circuit, for debugging unsatisfied circuits.
+///
+/// The public variables include the constant `F::one()` variable at index 0.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Assignment<F: Field> {
+    pub public_variables: Vec<F>,
+    pub private_variables: Vec<F>,
+}
+
+impl<F: Field> Assignment<F> {
+    /// Synthesizes the given circuit, and returns its (unpadded) witness assignment.
+    pub fn synthesize<C: ConstraintSynthesizer<F>>(circuit: &C) -> Result<Self, SynthesisError> {
+        let mut pcs = ConstraintSystem::new();
+        circuit.generate_constraints(&mut pcs)?;
+        Ok(pcs.export_assignment())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+    use snarkvm_r1cs::ConstraintSystem;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    type F = Fr;
+
+    /// A circuit that enforces `a * b = c`, with `c` as its public input.
+    struct MulCircuit {
+        a: F,
+        b: F,
+    }
+
+    impl ConstraintSynthesizer<F> for MulCircuit {
+        fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let a = cs.alloc(|| "a", || Ok(self.a))?;
+            let b = cs.alloc(|| "b", || Ok(self.b))?;
+            let c = cs.alloc_input(|| "c", || Ok(self.a * self.b))?;
+            cs.enforce(|| "a * b = c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_synthesize() {
+        let mut rng = TestRng::default();
+
+        let (a, b) = (F::rand(&mut rng), F::rand(&mut rng));
+        let assignment = Assignment::synthesize(&MulCircuit { a, b }).unwrap();
+        assert_eq!(assignment.public_variables, vec![F::one(), a * b]);
+        assert_eq!(assignment.private_variables, vec![a, b]);
+
+        // Ensure the assignment round-trips through serde.
+        let candidate: Assignment<F> = serde_json::from_str(&serde_json::to_string(&assignment).unwrap()).unwrap();
+        assert_eq!(assignment, candidate);
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::snark::marlin::ahp::matrices::make_matrices_square;
+use crate::snark::marlin::ahp::{matrices::make_matrices_square, prover::Assignment};
 use snarkvm_fields::Field;
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem as CS, Index as VarIndex, LinearCombination, Variable};
 
@@ -24,21 +24,52 @@ pub(crate) struct ConstraintSystem<F: Field> {
     pub(crate) num_public_variables: usize,
     pub(crate) num_private_variables: usize,
     pub(crate) num_constraints: usize,
+    /// The enforced constraints, which are only captured when debugging is enabled.
+    captured_constraints: Option<Vec<CapturedConstraint<F>>>,
+    /// The current namespace path, which is only tracked when debugging is enabled.
+    namespace: Vec<String>,
+}
+
+/// A constraint `a * b = c` recorded by the prover for debugging.
+struct CapturedConstraint<F: Field> {
+    name: String,
+    a: LinearCombination<F>,
+    b: LinearCombination<F>,
+    c: LinearCombination<F>,
 }
 
 impl<F: Field> ConstraintSystem<F> {
     pub(crate) fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+
+    /// Initializes a constraint system with room for the given number of public and private variables,
+    /// where `num_public_variables` includes the constant `F::one()` variable.
+    pub(crate) fn with_capacity(num_public_variables: usize, num_private_variables: usize) -> Self {
+        let mut public_variables = Vec::with_capacity(num_public_variables.max(1));
+        public_variables.push(F::one());
         Self {
-            public_variables: vec![F::one()],
-            private_variables: Vec::new(),
+            public_variables,
+            private_variables: Vec::with_capacity(num_private_variables),
             num_public_variables: 1usize,
             num_private_variables: 0usize,
             num_constraints: 0usize,
+            captured_constraints: None,
+            namespace: Vec::new(),
         }
     }
 
+    /// Initializes a constraint system that records every enforced constraint,
+    /// so that the witness can be checked with `check_constraints`.
+    pub(crate) fn with_constraint_capture() -> Self {
+        Self { captured_constraints: Some(Vec::new()), ..Self::new() }
+    }
+
     /// Formats the public input according to the requirements of the constraint
-    /// system
+    /// system, by prepending the constant `F::one()` to the given public input.
+    ///
+    /// An empty `public_input` is valid, and yields `[F::one()]`, so the formatted
+    /// input always contains at least one element.
     pub(crate) fn format_public_input(public_input: &[F]) -> Vec<F> {
         let mut input = vec![F::one()];
         input.extend_from_slice(public_input);
@@ -47,8 +78,64 @@ impl<F: Field> ConstraintSystem<F> {
 
     /// Takes in a previously formatted public input and removes the formatting
     /// imposed by the constraint system.
+    ///
+    /// This is the inverse of `format_public_input`; the formatted inputs `[F::one()]`
+    /// and `[]` both unformat to an empty public input.
     pub(crate) fn unformat_public_input(input: &[F]) -> Vec<F> {
-        input[1..].to_vec()
+        debug_assert!(input.is_empty() || input[0].is_one(), "The formatted input must start with one");
+        input.get(1..).map(<[F]>::to_vec).unwrap_or_default()
+    }
+
+    /// Returns the assigned value of the given variable, or `None` if it has not been allocated.
+    pub(crate) fn get_value(&self, variable: Variable) -> Option<F> {
+        match variable.get_unchecked() {
+            VarIndex::Public(index) => self.public_variables.get(index).copied(),
+            VarIndex::Private(index) => self.private_variables.get(index).copied(),
+        }
+    }
+
+    /// Evaluates the given linear combination against the current variable assignment.
+    ///
+    /// Panics if the linear combination references a variable that has not been allocated.
+    pub(crate) fn evaluate_lc(&self, lc: &LinearCombination<F>) -> F {
+        lc.as_ref()
+            .iter()
+            .map(|(variable, coeff)| {
+                let value =
+                    self.get_value(*variable).expect("The linear combination references an unallocated variable");
+                value * coeff
+            })
+            .sum()
+    }
+
+    /// Checks that the current assignment satisfies every captured constraint,
+    /// returning the first unsatisfied constraint as an error.
+    ///
+    /// This is a no-op if the constraint system was not initialized with `with_constraint_capture`.
+    pub(crate) fn check_constraints(&self) -> Result<(), SynthesisError> {
+        for (index, constraint) in self.captured_constraints.iter().flatten().enumerate() {
+            let a = self.evaluate_lc(&constraint.a);
+            let b = self.evaluate_lc(&constraint.b);
+            let c = self.evaluate_lc(&constraint.c);
+            if a * b != c {
+                return Err(SynthesisError::UnsatisfiedConstraint {
+                    index,
+                    name: constraint.name.clone(),
+                    a: a.to_string(),
+                    b: b.to_string(),
+                    c: c.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a copy of the current public and private variable assignment.
+    pub(crate) fn export_assignment(&self) -> Assignment<F> {
+        Assignment {
+            public_variables: self.public_variables.clone(),
+            private_variables: self.private_variables.clone(),
+        }
     }
 
     pub(crate) fn make_matrices_square(&mut self) {
@@ -69,7 +156,7 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         AR: AsRef<str>,
     {
         let index = self.num_private_variables;
-        self.num_private_variables += 1;
+        self.num_private_variables = index.checked_add(1).ok_or(SynthesisError::TooManyVariables)?;
 
         self.private_variables.push(f()?);
         Ok(Variable::new_unchecked(VarIndex::Private(index)))
@@ -83,14 +170,14 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         AR: AsRef<str>,
     {
         let index = self.num_public_variables;
-        self.num_public_variables += 1;
+        self.num_public_variables = index.checked_add(1).ok_or(SynthesisError::TooManyVariables)?;
 
         self.public_variables.push(f()?);
         Ok(Variable::new_unchecked(VarIndex::Public(index)))
     }
 
     #[inline]
-    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, _: LA, _: LB, _: LC)
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
     where
         A: FnOnce() -> AR,
         AR: AsRef<str>,
@@ -98,19 +185,42 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
         LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
     {
-        self.num_constraints += 1;
+        // `CS::enforce` is infallible, so an overflow cannot be surfaced as a `SynthesisError`.
+        self.num_constraints =
+            self.num_constraints.checked_add(1).expect("The number of constraints exceeds usize::MAX");
+
+        // Only construct the linear combinations when debugging, as the prover does not need them.
+        if let Some(constraints) = &mut self.captured_constraints {
+            let mut name = self.namespace.join("/");
+            if !name.is_empty() {
+                name.push('/');
+            }
+            name.push_str(annotation().as_ref());
+
+            constraints.push(CapturedConstraint {
+                name,
+                a: a(LinearCombination::zero()),
+                b: b(LinearCombination::zero()),
+                c: c(LinearCombination::zero()),
+            });
+        }
     }
 
-    fn push_namespace<NR, N>(&mut self, _: N)
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
     where
         NR: AsRef<str>,
         N: FnOnce() -> NR,
     {
-        // Do nothing; we don't care about namespaces in this context.
+        // Only track namespaces when debugging, as they are used to name the captured constraints.
+        if self.captured_constraints.is_some() {
+            self.namespace.push(name_fn().as_ref().to_string());
+        }
     }
 
     fn pop_namespace(&mut self) {
-        // Do nothing; we don't care about namespaces in this context.
+        if self.captured_constraints.is_some() {
+            self.namespace.pop();
+        }
     }
 
     fn get_root(&mut self) -> &mut Self::Root {
@@ -133,3 +243,151 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    type F = Fr;
+
+    #[test]
+    fn test_with_capacity() {
+        let cs = ConstraintSystem::<F>::with_capacity(4, 100);
+        assert_eq!(cs.public_variables, vec![F::one()]);
+        assert!(cs.public_variables.capacity() >= 4);
+        assert!(cs.private_variables.capacity() >= 100);
+        assert_eq!(cs.num_public_variables, 1);
+        assert_eq!(cs.num_private_variables, 0);
+        assert_eq!(cs.num_constraints, 0);
+
+        // Ensure the constant variable is allocated, even without capacity.
+        let cs = ConstraintSystem::<F>::new();
+        assert_eq!(cs.public_variables, vec![F::one()]);
+    }
+
+    #[test]
+    fn test_get_value() {
+        let mut rng = TestRng::default();
+
+        let mut cs = ConstraintSystem::<F>::new();
+        let (a_val, b_val) = (F::rand(&mut rng), F::rand(&mut rng));
+        let a = cs.alloc_input(|| "a", || Ok(a_val)).unwrap();
+        let b = cs.alloc(|| "b", || Ok(b_val)).unwrap();
+
+        assert_eq!(cs.get_value(ConstraintSystem::<F>::one()), Some(F::one()));
+        assert_eq!(cs.get_value(a), Some(a_val));
+        assert_eq!(cs.get_value(b), Some(b_val));
+        // Ensure unallocated variables resolve to `None`.
+        assert_eq!(cs.get_value(Variable::new_unchecked(VarIndex::Public(2))), None);
+        assert_eq!(cs.get_value(Variable::new_unchecked(VarIndex::Private(1))), None);
+    }
+
+    #[test]
+    fn test_evaluate_lc() {
+        let mut rng = TestRng::default();
+
+        let mut cs = ConstraintSystem::<F>::new();
+        let (a_val, b_val) = (F::rand(&mut rng), F::rand(&mut rng));
+        let a = cs.alloc_input(|| "a", || Ok(a_val)).unwrap();
+        let b = cs.alloc(|| "b", || Ok(b_val)).unwrap();
+
+        // The empty linear combination evaluates to zero.
+        assert!(cs.evaluate_lc(&LinearCombination::zero()).is_zero());
+
+        // Evaluate `2 + 3a - b`.
+        let two = F::one() + F::one();
+        let three = two + F::one();
+        let lc = LinearCombination::zero() + (two, ConstraintSystem::<F>::one()) + (three, a) - b;
+        assert_eq!(cs.evaluate_lc(&lc), two + three * a_val - b_val);
+    }
+
+    #[test]
+    fn test_check_constraints() {
+        let mut rng = TestRng::default();
+
+        let mut cs = ConstraintSystem::<F>::with_constraint_capture();
+        let a_val = F::rand(&mut rng);
+        let a = cs.alloc_input(|| "a", || Ok(a_val)).unwrap();
+        let b = cs.alloc(|| "b", || Ok(a_val.square())).unwrap();
+        let c = cs.alloc(|| "c", || Ok(a_val.double())).unwrap();
+        {
+            let mut cs = cs.ns(|| "gadget");
+            cs.enforce(|| "a * a = b", |lc| lc + a, |lc| lc + a, |lc| lc + b);
+            // Deliberately enforce an unsatisfied constraint.
+            cs.enforce(|| "a * a = c", |lc| lc + a, |lc| lc + a, |lc| lc + c);
+        }
+
+        match cs.check_constraints() {
+            Err(SynthesisError::UnsatisfiedConstraint { index, name, a, b, c }) => {
+                assert_eq!(index, 1);
+                assert_eq!(name, "gadget/a * a = c");
+                assert_eq!(a, a_val.to_string());
+                assert_eq!(b, a_val.to_string());
+                assert_eq!(c, a_val.double().to_string());
+            }
+            result => panic!("Expected an unsatisfied constraint, found {result:?}"),
+        }
+
+        // Ensure the constraints are not captured by default.
+        let mut cs = ConstraintSystem::<F>::new();
+        let a = cs.alloc(|| "a", || Ok(F::one())).unwrap();
+        cs.enforce(|| "a * a = 0", |lc| lc + a, |lc| lc + a, |lc| lc);
+        assert!(cs.check_constraints().is_ok());
+    }
+
+    #[test]
+    fn test_export_assignment() {
+        let mut rng = TestRng::default();
+
+        let mut cs = ConstraintSystem::<F>::new();
+        let (a_val, b_val) = (F::rand(&mut rng), F::rand(&mut rng));
+        cs.alloc_input(|| "a", || Ok(a_val)).unwrap();
+        cs.alloc(|| "b", || Ok(b_val)).unwrap();
+
+        let assignment = cs.export_assignment();
+        assert_eq!(assignment.public_variables, vec![F::one(), a_val]);
+        assert_eq!(assignment.private_variables, vec![b_val]);
+
+        // Ensure the assignment round-trips through serde.
+        let candidate: Assignment<F> = serde_json::from_str(&serde_json::to_string(&assignment).unwrap()).unwrap();
+        assert_eq!(assignment, candidate);
+    }
+
+    #[test]
+    fn test_alloc_too_many_variables() {
+        let mut cs = ConstraintSystem::<F>::new();
+
+        cs.num_private_variables = usize::MAX;
+        assert!(matches!(cs.alloc(|| "a", || Ok(F::one())), Err(SynthesisError::TooManyVariables)));
+        assert_eq!(cs.num_private_variables, usize::MAX);
+
+        cs.num_public_variables = usize::MAX;
+        assert!(matches!(cs.alloc_input(|| "b", || Ok(F::one())), Err(SynthesisError::TooManyVariables)));
+        assert_eq!(cs.num_public_variables, usize::MAX);
+    }
+
+    #[test]
+    fn test_format_unformat_empty_public_input() {
+        let formatted = ConstraintSystem::<F>::format_public_input(&[]);
+        assert_eq!(formatted, vec![F::one()]);
+        assert!(ConstraintSystem::<F>::unformat_public_input(&formatted).is_empty());
+        assert!(ConstraintSystem::<F>::unformat_public_input(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_format_unformat_public_input() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [1, 2, 10] {
+            let public_input: Vec<F> = (0..num_inputs).map(|_| F::rand(&mut rng)).collect();
+
+            let formatted = ConstraintSystem::<F>::format_public_input(&public_input);
+            assert_eq!(formatted.len(), num_inputs + 1);
+            assert_eq!(formatted[0], F::one());
+            assert_eq!(ConstraintSystem::<F>::unformat_public_input(&formatted), public_input);
+        }
+    }
+}
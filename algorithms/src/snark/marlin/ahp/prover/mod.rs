@@ -16,6 +16,9 @@
 
 #![allow(non_snake_case)]
 
+mod assignment;
+pub use assignment::*;
+
 mod constraint_system;
 pub(crate) use constraint_system::*;
 
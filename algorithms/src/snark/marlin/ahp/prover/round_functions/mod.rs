@@ -47,7 +47,10 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let (padded_public_variables, private_variables, z_a, z_b) = cfg_iter!(circuits)
             .map(|circuit| {
                 let constraint_time = start_timer!(|| "Generating constraints and witnesses");
-                let mut pcs = prover::ConstraintSystem::new();
+                // The index determines the padded number of variables, so reserve for them upfront.
+                let num_public_variables = index.index_info.num_public_inputs;
+                let num_private_variables = index.index_info.num_variables.saturating_sub(num_public_variables);
+                let mut pcs = prover::ConstraintSystem::with_capacity(num_public_variables, num_private_variables);
                 circuit.generate_constraints(&mut pcs)?;
                 end_timer!(constraint_time);
 
@@ -114,6 +117,18 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         Ok(state)
     }
+
+    /// Synthesizes the given circuit while recording its constraints, and checks that
+    /// the witness satisfies each of them, reporting the first unsatisfied constraint.
+    ///
+    /// This is intended for debugging circuits whose proofs fail to verify,
+    /// and is not part of the proving path.
+    pub fn check_constraints<C: ConstraintSynthesizer<F>>(circuit: &C) -> Result<(), AHPError> {
+        let mut pcs = prover::ConstraintSystem::with_constraint_capture();
+        circuit.generate_constraints(&mut pcs)?;
+        pcs.check_constraints()?;
+        Ok(())
+    }
 }
 
 fn inner_product<F: PrimeField>(
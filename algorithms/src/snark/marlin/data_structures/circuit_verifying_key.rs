@@ -38,6 +38,7 @@ use snarkvm_utilities::{
 use anyhow::Result;
 use core::{fmt, marker::PhantomData, str::FromStr};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
 
 /// Verification key for a specific index (i.e., R1CS matrices).
 #[derive(Debug, Clone, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
@@ -166,10 +167,30 @@ impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitVerifyingKey<E, MM> {
 }
 
 impl<E: PairingEngine, MM: MarlinMode> CircuitVerifyingKey<E, MM> {
+    /// The domain separator for the verifying key digest.
+    const DIGEST_DOMAIN: &'static [u8] = b"snarkvm.marlin.circuit_verifying_key.digest";
+
     /// Iterate over the commitments to indexed polynomials in `self`.
     pub fn iter(&self) -> impl Iterator<Item = &sonic_pc::Commitment<E>> {
         self.circuit_commitments.iter()
     }
+
+    /// Returns a 32-byte digest identifying the circuit that this verifying key attests to.
+    ///
+    /// The digest hashes the Marlin mode, the domain sizes, and the index commitments
+    /// (as given by `to_minimal_bits`), so it does not depend on how the key was serialized.
+    pub fn digest(&self) -> [u8; 32] {
+        let bits = self.to_minimal_bits();
+
+        let mut hasher = Sha256::new();
+        hasher.update(Self::DIGEST_DOMAIN);
+        hasher.update([MM::ZK as u8]);
+        hasher.update((bits.len() as u64).to_le_bytes());
+        for chunk in bits.chunks(8) {
+            hasher.update([chunk.iter().rev().fold(0u8, |byte, bit| (byte << 1) | *bit as u8)]);
+        }
+        hasher.finalize().into()
+    }
 }
 
 impl<E: PairingEngine, MM: MarlinMode> ToConstraintField<E::Fq> for CircuitVerifyingKey<E, MM> {
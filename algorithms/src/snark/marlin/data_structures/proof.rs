@@ -22,10 +22,16 @@ use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
     serialize::*,
+    string::String,
     FromBytes,
+    FromBytesDeserializer,
     ToBytes,
+    ToBytesSerializer,
 };
 
+use core::{fmt, str::FromStr};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Commitments<E: PairingEngine> {
     pub witness_commitments: Vec<WitnessCommitments<E>>,
@@ -318,3 +324,43 @@ impl<E: PairingEngine> FromBytes for Proof<E> {
         Self::deserialize_compressed(&mut r).map_err(|_| error("could not deserialize Proof"))
     }
 }
+
+impl<E: PairingEngine> FromStr for Proof<E> {
+    type Err = anyhow::Error;
+
+    #[inline]
+    fn from_str(proof_hex: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes_le(&hex::decode(proof_hex)?)
+    }
+}
+
+impl<E: PairingEngine> fmt::Display for Proof<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let proof_hex = hex::encode(self.to_bytes_le().expect("Failed to convert proof to bytes"));
+        write!(f, "{}", proof_hex)
+    }
+}
+
+impl<E: PairingEngine> Serialize for Proof<E> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, E: PairingEngine> Deserialize<'de> for Proof<E> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let s: String = Deserialize::deserialize(deserializer)?;
+                FromStr::from_str(&s).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proof"),
+        }
+    }
+}
@@ -65,6 +65,22 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode> MarlinSNAR
     /// Used to personalize the Fiat-Shamir RNG.
     pub const PROTOCOL_NAME: &'static [u8] = b"MARLIN-2019";
 
+    /// Verifies the proof against the given public input, after ensuring that the
+    /// digest of the verifying key matches the expected digest.
+    pub fn verify_with_digest<B: Borrow<[E::Fr]>>(
+        fs_parameters: &FS::Parameters,
+        verifying_key: &CircuitVerifyingKey<E, MM>,
+        expected_digest: &[u8; 32],
+        public_input: B,
+        proof: &Proof<E>,
+    ) -> Result<bool, SNARKError> {
+        // Ensure the verifying key is for the expected circuit.
+        if verifying_key.digest() != *expected_digest {
+            return Err(SNARKError::Message("The verifying key does not match the expected digest".to_string()));
+        }
+        <Self as SNARK>::verify(fs_parameters, verifying_key, public_input, proof)
+    }
+
     /// Generate the index-specific (i.e., circuit-specific) prover and verifier
     /// keys. This is a trusted setup.
     ///
@@ -67,7 +67,14 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<Constrai
 
 mod marlin {
     use super::*;
-    use crate::snark::marlin::{AHPForR1CS, CircuitVerifyingKey, MarlinHidingMode, MarlinNonHidingMode, MarlinSNARK};
+    use crate::snark::marlin::{
+        AHPForR1CS,
+        CircuitVerifyingKey,
+        MarlinHidingMode,
+        MarlinNonHidingMode,
+        MarlinSNARK,
+        Proof,
+    };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_utilities::rand::{TestRng, Uniform};
 
@@ -157,6 +164,53 @@ mod marlin {
                     }
                 }
 
+                pub(crate) fn test_digest(num_constraints: usize, num_variables: usize, expected_digest: &str) {
+                    use snarkvm_utilities::{FromBytes, ToBytes};
+
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree).unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    let a = Fr::rand(rng);
+                    let b = Fr::rand(rng);
+                    let mut c = a;
+                    c.mul_assign(&b);
+                    let mut d = c;
+                    d.mul_assign(&b);
+
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    // Ensure the digest matches the known answer, which pins its byte layout.
+                    assert_eq!(hex::encode(index_vk.digest()), expected_digest);
+
+                    // Ensure the digest is stable across serialization.
+                    let candidate =
+                        CircuitVerifyingKey::<Bls12_377, $marlin_mode>::read_le(&index_vk.to_bytes_le().unwrap()[..])
+                            .unwrap();
+                    assert_eq!(index_vk.digest(), candidate.digest());
+
+                    // Ensure a different circuit has a different digest.
+                    let other_circ =
+                        Circuit { a: Some(a), b: Some(b), num_constraints: num_constraints + 8, num_variables };
+                    let (_, other_vk) = $marlin_inst::circuit_setup(&universal_srs, &other_circ).unwrap();
+                    assert_ne!(index_vk.digest(), other_vk.digest());
+
+                    // Ensure the proof verifies only under the expected digest.
+                    let proof = $marlin_inst::prove(&fs_parameters, &index_pk, &circ, rng).unwrap();
+                    let digest = index_vk.digest();
+                    assert!(
+                        $marlin_inst::verify_with_digest(&fs_parameters, &index_vk, &digest, [c, d], &proof).unwrap()
+                    );
+                    let other_digest = other_vk.digest();
+                    assert!(
+                        $marlin_inst::verify_with_digest(&fs_parameters, &index_vk, &other_digest, [c, d], &proof)
+                            .is_err()
+                    );
+                }
+
                 pub(crate) fn test_serde_json(num_constraints: usize, num_variables: usize) {
                     use std::str::FromStr;
 
@@ -208,6 +262,46 @@ mod marlin {
                     assert_eq!(index_vk, CircuitVerifyingKey::read_le(&expected_bytes[..]).unwrap());
                     assert_eq!(index_vk, bincode::deserialize(&candidate_bytes[..]).unwrap());
                 }
+
+                pub(crate) fn test_proof_serialization(num_constraints: usize, num_variables: usize) {
+                    use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToBytes};
+                    use std::str::FromStr;
+
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree).unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    let circ =
+                        Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+
+                    let (index_pk, _index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    let proof = $marlin_inst::prove(&fs_parameters, &index_pk, &circ, rng).unwrap();
+
+                    // Ensure the proof round-trips through hex.
+                    let expected_string = proof.to_string();
+                    assert_eq!(hex::encode(proof.to_bytes_le().unwrap()), expected_string);
+                    assert_eq!(proof, Proof::from_str(&expected_string).unwrap());
+                    assert!(Proof::<Bls12_377>::from_str(&expected_string[2..]).is_err());
+
+                    // Ensure the proof round-trips through serde.
+                    let candidate_string = serde_json::to_string(&proof).unwrap();
+                    assert_eq!(candidate_string, format!("\"{expected_string}\""));
+                    assert_eq!(proof, serde_json::from_str(&candidate_string).unwrap());
+
+                    let expected_bytes = proof.to_bytes_le().unwrap();
+                    let candidate_bytes = bincode::serialize(&proof).unwrap();
+                    assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
+                    assert_eq!(proof, Proof::read_le(&expected_bytes[..]).unwrap());
+                    assert_eq!(proof, bincode::deserialize(&candidate_bytes[..]).unwrap());
+
+                    // Ensure the uncompressed encoding round-trips to the same proof.
+                    let mut uncompressed = Vec::new();
+                    proof.serialize_uncompressed(&mut uncompressed).unwrap();
+                    assert!(uncompressed.len() > expected_bytes.len());
+                    assert_eq!(proof, Proof::deserialize_uncompressed(&uncompressed[..]).unwrap());
+                }
             }
         };
     }
@@ -215,6 +309,18 @@ mod marlin {
     impl_marlin_test!(SonicPCTest, MarlinSonicInst, MarlinHidingMode);
     impl_marlin_test!(SonicPCPoswTest, MarlinSonicPoswInst, MarlinNonHidingMode);
 
+    #[test]
+    fn test_proof_serialization() {
+        SonicPCTest::test_proof_serialization(25, 25);
+        SonicPCPoswTest::test_proof_serialization(25, 25);
+    }
+
+    #[test]
+    fn test_circuit_verifying_key_digest() {
+        SonicPCTest::test_digest(25, 25, "d364581bd70c31e0d7a1596de87a32a656d0954daa89cd494ad3216d114b3791");
+        SonicPCPoswTest::test_digest(25, 25, "5f4e415e05dbc29851d37fe82211edabeb2f390eff80d7f3e388bfcaa648fe8f");
+    }
+
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
         let num_constraints = 100;
@@ -101,6 +101,18 @@ impl<F: PrimeField> Assignment<F> {
     pub fn num_constraints(&self) -> u64 {
         self.constraints.len() as u64
     }
+
+    /// Returns the number of non-zero entries in the `A`, `B`, and `C` matrices,
+    /// once the assignment is converted into a `snarkvm_r1cs`-compliant constraint system.
+    pub fn num_nonzeros(&self) -> (u64, u64, u64) {
+        // Each linear combination contributes one entry per variable, and one entry for its constant.
+        let num_nonzeros = |lc: &AssignmentLC<F>| {
+            1 + lc.terms.keys().filter(|variable| !matches!(variable, AssignmentVariable::Constant(..))).count() as u64
+        };
+        self.constraints.iter().fold((0, 0, 0), |(num_a, num_b, num_c), (a, b, c)| {
+            (num_a + num_nonzeros(a), num_b + num_nonzeros(b), num_c + num_nonzeros(c))
+        })
+    }
 }
 
 impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for Assignment<F> {
@@ -264,6 +276,11 @@ mod tests {
             assert_eq!(assignment.num_public() + 1, cs.num_public_variables() as u64);
             assert_eq!(assignment.num_private(), cs.num_private_variables() as u64);
             assert_eq!(assignment.num_constraints(), cs.num_constraints() as u64);
+            let (num_non_zero_a, num_non_zero_b, num_non_zero_c) = cs.num_non_zero();
+            assert_eq!(
+                assignment.num_nonzeros(),
+                (num_non_zero_a as u64, num_non_zero_b as u64, num_non_zero_c as u64)
+            );
             assert!(cs.is_satisfied());
         }
     }
@@ -19,12 +19,18 @@ use super::*;
 impl<A: Aleo> Record<A, Plaintext<A>> {
     /// Returns the record commitment.
     pub fn to_commitment(&self, program_id: &ProgramID<A>, record_name: &Identifier<A>) -> Field<A> {
+        // Compute the BHP hash of the program record.
+        A::hash_bhp1024(&self.to_commitment_preimage(program_id, record_name))
+    }
+
+    /// Returns the input to the record commitment, as `(program_id || record_name || record)` in little-endian bits.
+    /// This must match the layout of the console record commitment.
+    pub fn to_commitment_preimage(&self, program_id: &ProgramID<A>, record_name: &Identifier<A>) -> Vec<Boolean<A>> {
         // Construct the input as `(program_id || record_name || record)`.
         let mut input = program_id.to_bits_le();
         input.extend(record_name.to_bits_le());
         input.extend(self.to_bits_le());
-        // Compute the BHP hash of the program record.
-        A::hash_bhp1024(&input)
+        input
     }
 }
 
@@ -34,3 +40,49 @@ impl<A: Aleo> Record<A, Ciphertext<A>> {
         A::halt("Illegal operation: Record::to_commitment() cannot be invoked on the `Ciphertext` variant.")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    #[test]
+    fn test_to_commitment_matches_console() -> Result<()> {
+        let program_id = console::ProgramID::<<Circuit as Environment>::Network>::from_str("token.aleo")?;
+        let record_name = console::Identifier::<<Circuit as Environment>::Network>::from_str("token")?;
+        let record = console::Record::<
+            <Circuit as Environment>::Network,
+            console::Plaintext<<Circuit as Environment>::Network>,
+        >::from_str(
+            r"{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    gates: 5u64.private,
+    a: true.private,
+    b: 123456789field.public,
+    c: {
+        d: 0group.private
+    },
+    _nonce: 0group.public
+}",
+        )?;
+
+        // Compute the console commitment preimage and commitment.
+        let expected_preimage = record.to_commitment_preimage(&program_id, &record_name);
+        let expected_commitment = record.to_commitment(&program_id, &record_name)?;
+
+        // Compute the circuit commitment preimage and commitment, over a private record.
+        let candidate_record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record);
+        let candidate_program_id = ProgramID::<Circuit>::new(Mode::Constant, program_id);
+        let candidate_record_name = Identifier::<Circuit>::new(Mode::Constant, record_name);
+        let candidate_preimage = candidate_record.to_commitment_preimage(&candidate_program_id, &candidate_record_name);
+        let candidate_commitment = candidate_record.to_commitment(&candidate_program_id, &candidate_record_name);
+
+        // Ensure the circuit and console preimages and commitments match.
+        assert_eq!(expected_preimage, candidate_preimage.eject_value());
+        assert_eq!(expected_commitment, candidate_commitment.eject_value());
+        assert!(Circuit::is_satisfied());
+
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -86,14 +86,25 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
 
         // Compute and store the hashes for each level, iterating from the penultimate level to the root level.
         let mut start_index = num_nodes;
+        // Initialize the number of nodes in the current level that are not entirely padding.
+        let mut num_filled_nodes = leaves.len();
+        // Initialize the hash of an empty subtree rooted at the current level.
+        let mut empty_subtree_hash = empty_hash;
         // Compute the start index of the current level.
         while let Some(start) = parent(start_index) {
             // Compute the end index of the current level.
             let end = left_child(start);
-            // Construct the children for each node in the current level.
-            let tuples = (start..end).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
-            // Compute and store the hashes for each node in the current level.
-            tree[start..end].copy_from_slice(&path_hasher.hash_all_children(&tuples)?);
+            // Update the number of filled nodes and the empty subtree hash for the current level.
+            num_filled_nodes = (num_filled_nodes + 1) / 2;
+            empty_subtree_hash = path_hasher.hash_children(&empty_subtree_hash, &empty_subtree_hash)?;
+            // Compute the end index of the filled nodes in the current level.
+            let middle = start + num_filled_nodes;
+            // Construct the children for each filled node in the current level.
+            let tuples = (start..middle).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
+            // Compute and store the hashes for each filled node in the current level.
+            tree[start..middle].copy_from_slice(&path_hasher.hash_all_children(&tuples)?);
+            // Store the empty subtree hash for each remaining node in the current level.
+            tree[middle..end].fill(empty_subtree_hash);
             // Update the start index for the next level.
             start_index = start;
         }
@@ -172,6 +183,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             middle_index,
             start_precompute_index,
             middle_precompute_index,
+            self.number_of_leaves + new_leaves.len(),
         )?;
 
         // Compute the root hash, by iterating from the root level up to `DEPTH`.
@@ -267,6 +279,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             middle_index,
             start_precompute_index,
             middle_precompute_index,
+            updated_number_of_leaves,
         )?;
 
         // Compute the root hash, by iterating from the root level up to `DEPTH`.
@@ -394,6 +407,9 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     ///  start_index      middle_index                              end_index
     ///  start_precompute_index         middle_precompute_index     end_index
     /// ```
+    ///
+    /// Nodes whose subtrees only contain padding are filled with the empty subtree hash of their level,
+    /// so the number of hashes computed is proportional to the number of new leaves, not the tree size.
     #[inline]
     fn compute_updated_tree(
         &self,
@@ -402,14 +418,21 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         mut middle_index: usize,
         mut start_precompute_index: usize,
         mut middle_precompute_index: Option<usize>,
+        mut num_filled_nodes: usize,
     ) -> Result<()> {
         // Initialize a timer for the while loop.
         let timer = timer!("MerkleTree::compute_updated_tree");
 
+        // Initialize the hash of an empty subtree rooted at the current level.
+        let mut empty_subtree_hash = self.empty_hash;
+
         // Compute and store the hashes for each level, iterating from the penultimate level to the root level.
         while let (Some(start), Some(middle)) = (parent(start_index), parent(middle_index)) {
             // Compute the end index of the current level.
             let end = left_child(start);
+            // Update the number of filled nodes and the empty subtree hash for the current level.
+            num_filled_nodes = (num_filled_nodes + 1) / 2;
+            empty_subtree_hash = self.path_hasher.hash_children(&empty_subtree_hash, &empty_subtree_hash)?;
 
             // If the current level has precomputed indices, copy them instead of recomputing them.
             if let Some(start_precompute) = parent(start_precompute_index) {
@@ -466,25 +489,32 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
                     );
                 }
             } else {
+                // Compute the end index of the filled nodes in the current level.
+                let filled_end = core::cmp::max(middle, start + num_filled_nodes);
                 // Construct the children for the new indices in the current level.
-                let tuples = (middle..end).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
+                let tuples =
+                    (middle..filled_end).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
                 // Process the indices that need to be computed for the current level.
                 // If any level requires computing more than 100 nodes, borrow the tree for performance.
                 match tuples.len() >= 100 {
                     // Option 1: Borrow the tree to compute and store the hashes for the new indices in the current level.
-                    true => cfg_iter_mut!(tree[middle..end]).zip_eq(cfg_iter!(tuples)).try_for_each(
+                    true => cfg_iter_mut!(tree[middle..filled_end]).zip_eq(cfg_iter!(tuples)).try_for_each(
                         |(node, (left, right))| {
                             *node = self.path_hasher.hash_children(left, right)?;
                             Ok::<_, Error>(())
                         },
                     )?,
                     // Option 2: Compute and store the hashes for the new indices in the current level.
-                    false => tree[middle..end].iter_mut().zip_eq(&tuples).try_for_each(|(node, (left, right))| {
-                        *node = self.path_hasher.hash_children(left, right)?;
-                        Ok::<_, Error>(())
-                    })?,
+                    false => {
+                        tree[middle..filled_end].iter_mut().zip_eq(&tuples).try_for_each(|(node, (left, right))| {
+                            *node = self.path_hasher.hash_children(left, right)?;
+                            Ok::<_, Error>(())
+                        })?
+                    }
                 }
-                lap!(timer, "Compute: {middle} -> {end}");
+                // Store the empty subtree hash for each remaining node in the current level.
+                tree[filled_end..end].fill(empty_subtree_hash);
+                lap!(timer, "Compute: {middle} -> {filled_end} (Padding: {filled_end} -> {end})");
             }
 
             // Update the start index for the next level.
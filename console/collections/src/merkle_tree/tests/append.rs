@@ -583,6 +583,116 @@ fn test_merkle_tree_depth_4_poseidon() -> Result<()> {
     )
 }
 
+/// Computes the Merkle root of the given leaves by hashing every node of the padded tree,
+/// independently of the `MerkleTree` construction.
+fn compute_root_by_full_rehash<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<Field<E>> {
+    let empty_hash = path_hasher.hash_empty()?;
+
+    // Hash the leaves, and pad them with empty hashes up to the next power of two.
+    let mut level = leaf_hasher.hash_leaves(leaves)?;
+    level.resize(leaves.len().next_power_of_two(), empty_hash);
+
+    // Hash each level, including the padding, until the root is reached.
+    let mut tree_depth = 0;
+    while level.len() > 1 {
+        level = level.chunks(2).map(|pair| path_hasher.hash_children(&pair[0], &pair[1])).collect::<Result<_>>()?;
+        tree_depth += 1;
+    }
+
+    // Hash the root with the empty hash up to `DEPTH`.
+    let mut root = level[0];
+    for _ in tree_depth..DEPTH {
+        root = path_hasher.hash_children(&root, &empty_hash)?;
+    }
+    Ok(root)
+}
+
+/// Runs the following test:
+/// 1. Construct a Merkle tree by appending the leaves in batches of the given size.
+/// 2. Checks that the tree and the Merkle root match the tree constructed from scratch,
+///    and that the Merkle root matches the root computed by rehashing the full tree.
+fn check_merkle_tree_incremental_append<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    batch_size: usize,
+) -> Result<()> {
+    // Construct the Merkle tree incrementally.
+    let mut merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?;
+    for batch in leaves.chunks(batch_size) {
+        merkle_tree.append(batch)?;
+
+        // Construct the Merkle tree from scratch.
+        let expected =
+            MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..merkle_tree.number_of_leaves])?;
+        // Ensure the trees are equivalent.
+        assert_eq!(expected.root(), merkle_tree.root());
+        assert_eq!(expected.tree(), merkle_tree.tree());
+        // Ensure the root matches the root of the fully rehashed tree.
+        let expected_root = compute_root_by_full_rehash::<E, LH, PH, DEPTH>(
+            leaf_hasher,
+            path_hasher,
+            &leaves[..merkle_tree.number_of_leaves],
+        )?;
+        assert_eq!(&expected_root, merkle_tree.root());
+    }
+    assert_eq!(leaves.len(), merkle_tree.number_of_leaves);
+
+    // Remove the leaves in batches, and ensure the trees remain equivalent.
+    while merkle_tree.number_of_leaves > 0 {
+        merkle_tree.remove_last_n(core::cmp::min(batch_size, merkle_tree.number_of_leaves))?;
+
+        // Construct the Merkle tree from scratch.
+        let expected =
+            MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..merkle_tree.number_of_leaves])?;
+        // Ensure the trees are equivalent.
+        assert_eq!(expected.root(), merkle_tree.root());
+        assert_eq!(expected.tree(), merkle_tree.tree());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_incremental_append_poseidon() -> Result<()> {
+    const DEPTH: u8 = 32;
+
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Sample the leaves.
+    let leaves = (0..70).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+
+    // Check the Merkle tree for a variety of sizes, including those crossing powers of two.
+    for batch_size in [1, 2, 3, 7, 16, 33] {
+        check_merkle_tree_incremental_append::<CurrentEnvironment, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &leaves,
+            batch_size,
+        )?;
+    }
+    Ok(())
+}
+
 /// Use `cargo test profiler --features timer` to run this test.
 #[ignore]
 #[test]
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use core::mem::size_of;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns an approximation of the number of bytes of memory used by `self`.
+    ///
+    /// This is the size of the record struct, plus the heap allocations of its data entries.
+    pub fn approx_memory_size(&self) -> usize {
+        self.approx_memory_size_with(plaintext_heap_size)
+    }
+}
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns an approximation of the number of bytes of memory used by `self`.
+    ///
+    /// This is the size of the record struct, plus the heap allocations of its data entries.
+    pub fn approx_memory_size(&self) -> usize {
+        self.approx_memory_size_with(ciphertext_heap_size)
+    }
+}
+
+impl<N: Network, Private: Visibility> Record<N, Private> {
+    /// Returns an approximation of the number of bytes of memory used by `self`,
+    /// given a function that returns the heap size of a private value.
+    fn approx_memory_size_with(&self, private_heap_size: impl Fn(&Private) -> usize) -> usize {
+        // Literals, identifiers, and addresses are stored inline, and are included in the struct size.
+        let mut size = size_of::<Self>();
+        if let Owner::Private(owner) = &self.owner {
+            size += private_heap_size(owner);
+        }
+        if let Balance::Private(gates) = &self.gates {
+            size += private_heap_size(gates);
+        }
+
+        // Compute the size of the data entries.
+        size += self.data.len() * index_map_entry_size::<Identifier<N>, Entry<N, Private>>();
+        for entry in self.data.values() {
+            size += match entry {
+                Entry::Constant(plaintext) | Entry::Public(plaintext) => plaintext_heap_size(plaintext),
+                Entry::Private(private) => private_heap_size(private),
+            };
+        }
+        size
+    }
+}
+
+/// Returns the approximate number of bytes used by one entry of an `IndexMap`,
+/// which stores the hash, key, and value in its entries, and an index in its table.
+const fn index_map_entry_size<K, V>() -> usize {
+    size_of::<(usize, K, V)>() + size_of::<usize>()
+}
+
+/// Returns the number of heap bytes used by the given plaintext, including its cached bits.
+fn plaintext_heap_size<N: Network>(plaintext: &Plaintext<N>) -> usize {
+    match plaintext {
+        Plaintext::Literal(_, bits) => bits.get().map_or(0, Vec::len),
+        Plaintext::Struct(members, bits) => {
+            members.len() * index_map_entry_size::<Identifier<N>, Plaintext<N>>()
+                + members.values().map(plaintext_heap_size).sum::<usize>()
+                + bits.get().map_or(0, Vec::len)
+        }
+    }
+}
+
+/// Returns the number of heap bytes used by the given ciphertext.
+fn ciphertext_heap_size<N: Network>(ciphertext: &Ciphertext<N>) -> usize {
+    ciphertext.len() * size_of::<Field<N>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_approx_memory_size() -> Result<()> {
+        // Ensure a record without data entries is the size of the struct.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, gates: 5u64.public, _nonce: 0group.public }",
+        )?;
+        assert_eq!(record.approx_memory_size(), size_of::<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>());
+
+        // Ensure private literals are stored inline, and only the data entries and struct members use the heap.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, a: 1field.private, b: { c: 2u8.public, d: 3u8.public }, _nonce: 0group.public }",
+        )?;
+        let entry_size =
+            index_map_entry_size::<Identifier<CurrentNetwork>, Entry<CurrentNetwork, Plaintext<CurrentNetwork>>>();
+        let member_size = index_map_entry_size::<Identifier<CurrentNetwork>, Plaintext<CurrentNetwork>>();
+        let expected =
+            size_of::<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>() + 2 * entry_size + 2 * member_size;
+        assert_eq!(record.approx_memory_size(), expected);
+
+        // Ensure the ciphertext is charged for its field elements.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, a: 1field.private, _nonce: 0group.public }",
+        )?;
+        let num_fields = record.num_randomizers()? as usize;
+        let ciphertext = record.encrypt_symmetric(&Field::from_u64(1))?;
+        let expected = size_of::<Record<CurrentNetwork, Ciphertext<CurrentNetwork>>>()
+            + index_map_entry_size::<Identifier<CurrentNetwork>, Entry<CurrentNetwork, Ciphertext<CurrentNetwork>>>()
+            + num_fields * size_of::<Field<CurrentNetwork>>();
+        assert_eq!(ciphertext.approx_memory_size(), expected);
+        Ok(())
+    }
+}
@@ -35,7 +35,12 @@ impl<N: Network, Private: Visibility> FromBytes for Record<N, Private> {
             // Read the entry bytes.
             let bytes = (0..num_bytes).map(|_| u8::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
             // Recover the entry value.
-            let entry = Entry::read_le(&mut bytes.as_slice())?;
+            let mut remaining = bytes.as_slice();
+            let entry = Entry::read_le(&mut remaining)?;
+            // Ensure the entry consumed exactly the bytes it was given.
+            if !remaining.is_empty() {
+                return Err(error(format!("Failed to parse record: found {} trailing entry bytes", remaining.len())));
+            }
             // Add the entry.
             data.insert(identifier, entry);
         }
@@ -87,6 +92,33 @@ impl<N: Network, Private: Visibility> ToBytes for Record<N, Private> {
     }
 }
 
+impl<N: Network, Private: Visibility> Record<N, Private> {
+    /// The version of the record byte layout.
+    const BYTES_VERSION: u8 = 0;
+
+    /// Returns the record bytes, prefixed with a one-byte layout version.
+    pub fn to_versioned_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = vec![Self::BYTES_VERSION];
+        self.write_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Reads a record from bytes that are prefixed with a one-byte layout version.
+    pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Self> {
+        match bytes.split_first() {
+            Some((&Self::BYTES_VERSION, mut reader)) => {
+                // Read the record.
+                let record = Self::read_le(&mut reader)?;
+                // Ensure there are no trailing bytes.
+                ensure!(reader.is_empty(), "Found {} trailing bytes after the record", reader.len());
+                Ok(record)
+            }
+            Some((version, _)) => bail!("Unknown record bytes version {version}"),
+            None => bail!("Missing the record bytes version"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,4 +139,66 @@ mod tests {
         assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::read_le(&expected_bytes[1..]).is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_versioned_bytes() -> Result<()> {
+        // Construct a new record.
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+
+        // Check the versioned byte representation.
+        let versioned_bytes = expected.to_versioned_bytes()?;
+        assert_eq!(expected.to_bytes_le()?, versioned_bytes[1..]);
+        assert_eq!(expected, Record::from_versioned_bytes(&versioned_bytes)?);
+
+        // Ensure an unknown version, a missing version, and trailing bytes are rejected.
+        let mut unknown_version = versioned_bytes.clone();
+        unknown_version[0] = 1;
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_versioned_bytes(&unknown_version).is_err());
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_versioned_bytes(&[]).is_err());
+        let mut trailing_bytes = versioned_bytes;
+        trailing_bytes.push(0);
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_versioned_bytes(&trailing_bytes).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_bytes() -> Result<()> {
+        // Construct a new record.
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+
+        // Check the byte conversion.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Record::try_from(expected_bytes.as_slice())?);
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::try_from(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_rejects_trailing_entry_bytes() -> Result<()> {
+        // Construct a new record.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+
+        // Re-encode the record, appending a stray byte to the entry.
+        let mut bytes = record.owner().to_bytes_le()?;
+        bytes.extend(record.gates().to_bytes_le()?);
+        bytes.extend(1u8.to_bytes_le()?);
+        for (identifier, entry) in record.data() {
+            let mut entry_bytes = entry.to_bytes_le()?;
+            entry_bytes.push(0u8);
+            bytes.extend(identifier.to_bytes_le()?);
+            bytes.extend(u16::try_from(entry_bytes.len())?.to_bytes_le()?);
+            bytes.extend(entry_bytes);
+        }
+        bytes.extend(record.nonce().to_bytes_le()?);
+
+        // Ensure the trailing entry byte is rejected.
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
 }
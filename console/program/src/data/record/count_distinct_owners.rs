@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the number of distinct owner addresses among the given records,
+    /// regardless of whether each owner is public or private.
+    pub fn count_distinct_owners(records: &[Self]) -> usize {
+        records.iter().map(|record| **record.owner()).collect::<IndexSet<Address<N>>>().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_record(owner: &str) -> Result<Record<CurrentNetwork, Plaintext<CurrentNetwork>>> {
+        Record::from_str(&format!("{{ owner: {owner}, gates: 5u64.private, _nonce: 0group.public }}"))
+    }
+
+    #[test]
+    fn test_count_distinct_owners() -> Result<()> {
+        let alice = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+        let bob = "aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add";
+
+        // Ensure an empty batch has no owners.
+        assert_eq!(0, Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::count_distinct_owners(&[]));
+
+        // Ensure the same address is counted once, regardless of its visibility.
+        let records = vec![
+            sample_record(&format!("{alice}.private"))?,
+            sample_record(&format!("{alice}.public"))?,
+            sample_record(&format!("{bob}.private"))?,
+            sample_record(&format!("{alice}.private"))?,
+        ];
+        assert_eq!(2, Record::count_distinct_owners(&records));
+        assert_eq!(1, Record::count_distinct_owners(&records[..2]));
+        Ok(())
+    }
+}
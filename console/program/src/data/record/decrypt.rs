@@ -26,6 +26,9 @@ impl<N: Network> Record<N, Ciphertext<N>> {
     }
 
     /// Decrypts `self` into plaintext using the given record view key.
+    ///
+    /// This is the inverse of `Record::encrypt_symmetric`, and may be used to open
+    /// a record that was sealed under a one-time symmetric key.
     pub fn decrypt_symmetric(&self, record_view_key: &Field<N>) -> Result<Record<N, Plaintext<N>>> {
         // Determine the number of randomizers needed to encrypt the record.
         let num_randomizers = self.num_randomizers()?;
@@ -168,4 +171,50 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_encrypt_and_decrypt_symmetric() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let record_name = Identifier::<CurrentNetwork>::from_str("token")?;
+
+        for _ in 0..ITERATIONS {
+            // Sample an address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Prepare the record.
+            let randomizer = Scalar::rand(&mut rng);
+            let record = Record {
+                owner: Owner::Private(Plaintext::from(Literal::Address(address))),
+                gates: Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(&mut rng) >> 12)))),
+                data: IndexMap::from_iter(
+                    vec![(
+                        Identifier::from_str("a")?,
+                        Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+                    )]
+                    .into_iter(),
+                ),
+                nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+            };
+
+            // Seal the record under a one-time symmetric key.
+            let symmetric_key = Field::rand(&mut rng);
+            let ciphertext = record.encrypt_symmetric(&symmetric_key)?;
+
+            // Ensure the record opens under the symmetric key, with the same commitment.
+            let candidate = ciphertext.decrypt_symmetric(&symmetric_key)?;
+            assert_eq!(record, candidate);
+            assert_eq!(
+                record.to_commitment(&program_id, &record_name)?,
+                candidate.to_commitment(&program_id, &record_name)?
+            );
+
+            // Ensure the record does not open under the owner's view key.
+            assert!(ciphertext.decrypt(&view_key).map_or(true, |candidate| candidate != record));
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The components that differ between two records.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecordDiff {
+    /// Whether the owners differ.
+    pub owner: bool,
+    /// Whether the gates differ.
+    pub gates: bool,
+    /// Whether the data (entry names, entries, or their order) differs.
+    pub data: bool,
+    /// Whether the nonces differ.
+    pub nonce: bool,
+}
+
+impl RecordDiff {
+    /// Returns `true` if no component differs.
+    pub const fn is_empty(&self) -> bool {
+        !(self.owner || self.gates || self.data || self.nonce)
+    }
+}
+
+impl<N: Network, Private: Visibility<Boolean = Boolean<N>>> Record<N, Private> {
+    /// Returns the components that differ between `self` and `other`.
+    pub fn diff(&self, other: &Self) -> RecordDiff {
+        // Check the data, ensuring the number of entries matches before comparing them pairwise.
+        let data = self.data.len() != other.data.len()
+            || self.data.iter().zip(other.data.iter()).any(|((name_a, entry_a), (name_b, entry_b))| {
+                *(name_a.is_not_equal(name_b) | entry_a.is_not_equal(entry_b))
+            });
+
+        RecordDiff {
+            owner: *self.owner.is_not_equal(&other.owner),
+            gates: *self.gates.is_not_equal(&other.gates),
+            data,
+            nonce: *self.nonce.is_not_equal(&other.nonce),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_record(gates: &str, data: &str, nonce: &str) -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+        Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private, gates: {gates}, {data}, _nonce: {nonce} }}"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_diff() {
+        let record = sample_record("5u64.private", "a: true.private, b: 1field.public", "0group.public");
+
+        // Ensure a record does not differ from itself.
+        assert!(record.diff(&record).is_empty());
+
+        // Ensure a change to the gates is detected.
+        let candidate = sample_record("6u64.private", "a: true.private, b: 1field.public", "0group.public");
+        assert_eq!(record.diff(&candidate), RecordDiff { gates: true, ..Default::default() });
+
+        // Ensure a change to the visibility of an entry is detected.
+        let candidate = sample_record("5u64.private", "a: true.public, b: 1field.public", "0group.public");
+        assert_eq!(record.diff(&candidate), RecordDiff { data: true, ..Default::default() });
+
+        // Ensure a change to the number of entries is detected.
+        let candidate = sample_record("5u64.private", "a: true.private", "0group.public");
+        assert_eq!(record.diff(&candidate), RecordDiff { data: true, ..Default::default() });
+
+        // Ensure a change to the owner and nonce is detected.
+        let owner = Owner::Public(
+            Address::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah").unwrap(),
+        );
+        let nonce = *record.nonce() + Group::generator();
+        let candidate = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+            owner,
+            record.gates().clone(),
+            record.data().clone(),
+            nonce,
+        )
+        .unwrap();
+        assert_eq!(record.diff(&candidate), RecordDiff { owner: true, nonce: true, ..Default::default() });
+    }
+}
@@ -31,6 +31,12 @@ impl<N: Network> Record<N, Plaintext<N>> {
     }
 
     /// Encrypts `self` under the given record view key.
+    ///
+    /// The record view key may be any field element, including a one-time symmetric key
+    /// that is not derived from an account, which "seals" the record to the holder of the key.
+    /// In this case, the record can no longer be decrypted with the owner's view key, and
+    /// the security of the record rests entirely on the secrecy and uniqueness of the key.
+    /// The record commitment is computed over the plaintext, so it is unaffected by this choice.
     pub fn encrypt_symmetric(&self, record_view_key: &Field<N>) -> Result<Record<N, Ciphertext<N>>> {
         // Determine the number of randomizers needed to encrypt the record.
         let num_randomizers = self.num_randomizers()?;
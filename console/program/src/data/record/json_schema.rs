@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+
+/// The regular expression for a record plaintext, as printed by `Display`.
+const RECORD_PLAINTEXT_PATTERN: &str = concat!(
+    r"^\{\s*owner:\s*aleo1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{58}\.(constant|public|private),",
+    r"\s*gates:\s*[0-9][0-9_]*u64\.(constant|public|private),",
+    r"[\s\S]*_nonce:\s*-?[0-9]+group\.public\s*\}$",
+);
+
+/// The regular expression for a bech32m-encoded record ciphertext.
+const RECORD_CIPHERTEXT_PATTERN: &str = "^record1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]+$";
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the JSON schema of the record plaintext, as a JSON value.
+    pub fn json_schema_value() -> serde_json::Value {
+        serde_json::to_value(schemars::schema_for!(Self)).expect("Failed to serialize the record plaintext schema")
+    }
+}
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns the JSON schema of the record ciphertext, as a JSON value.
+    pub fn json_schema_value() -> serde_json::Value {
+        serde_json::to_value(schemars::schema_for!(Self)).expect("Failed to serialize the record ciphertext schema")
+    }
+}
+
+impl<N: Network> JsonSchema for Record<N, Plaintext<N>> {
+    fn schema_name() -> String {
+        "RecordPlaintext".to_string()
+    }
+
+    /// Describes the record plaintext, which is serialized as a string in human-readable formats.
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                title: Some("Record plaintext".to_string()),
+                description: Some(
+                    "A record plaintext of the form `{ owner: <address>.<visibility>, gates: <u64>.<visibility>, \
+                     <identifier>: <entry>, ..., _nonce: <group>.public }`, where each visibility is one of \
+                     `constant`, `public`, or `private`."
+                        .to_string(),
+                ),
+                ..Default::default()
+            })),
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(RECORD_PLAINTEXT_PATTERN.to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+impl<N: Network> JsonSchema for Record<N, Ciphertext<N>> {
+    fn schema_name() -> String {
+        "RecordCiphertext".to_string()
+    }
+
+    /// Describes the record ciphertext, which is serialized as a bech32m string in human-readable formats.
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                title: Some("Record ciphertext".to_string()),
+                description: Some(
+                    "A bech32m-encoded record ciphertext, with the human-readable part `record`.".to_string(),
+                ),
+                ..Default::default()
+            })),
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(RECORD_CIPHERTEXT_PATTERN.to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use regex::Regex;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the regular expression in the `pattern` of the given schema.
+    fn pattern(schema: &serde_json::Value) -> Regex {
+        Regex::new(schema["pattern"].as_str().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_record_plaintext_json_schema() -> Result<()> {
+        let schema = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::json_schema_value();
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["title"], "Record plaintext");
+        let pattern = pattern(&schema);
+
+        // Ensure a serialized record plaintext matches the schema pattern.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+        let json = serde_json::to_value(&record)?;
+        assert!(pattern.is_match(json.as_str().unwrap()));
+
+        // Ensure other braced text does not match the schema pattern.
+        assert!(!pattern.is_match("{}"));
+        assert!(!pattern.is_match("{ owner: aleo1abc.private, gates: 5u64.private, _nonce: 0group.public }"));
+        assert!(!pattern.is_match(&json.as_str().unwrap().replace("_nonce", "nonce")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_ciphertext_json_schema() -> Result<()> {
+        let schema = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::json_schema_value();
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["title"], "Record ciphertext");
+        let pattern = pattern(&schema);
+
+        // Ensure a serialized record ciphertext matches the schema pattern.
+        let mut rng = TestRng::default();
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, _nonce: 0group.public }",
+        )?;
+        let randomizer = Scalar::rand(&mut rng);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext_with_randomizer(
+            record.owner().clone(),
+            record.gates().clone(),
+            record.data().clone(),
+            &randomizer,
+        )?;
+        let ciphertext = record.encrypt(randomizer)?;
+        let json = serde_json::to_value(&ciphertext)?;
+        assert!(pattern.is_match(json.as_str().unwrap()));
+
+        // Ensure non-bech32 characters do not match the schema pattern.
+        assert!(!pattern.is_match(&format!("{}_", json.as_str().unwrap())));
+        assert!(!pattern.is_match("record1"));
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use once_cell::sync::OnceCell;
+
+/// A record ciphertext that is decrypted on demand, one component at a time.
+pub struct LazyRecord<N: Network> {
+    /// The record ciphertext.
+    ciphertext: Record<N, Ciphertext<N>>,
+    /// The record view key.
+    record_view_key: Field<N>,
+    /// The decrypted owner.
+    owner: OnceCell<Owner<N, Plaintext<N>>>,
+    /// The decrypted gates.
+    gates: OnceCell<Balance<N, Plaintext<N>>>,
+    /// The decrypted record.
+    record: OnceCell<Record<N, Plaintext<N>>>,
+}
+
+impl<N: Network> LazyRecord<N> {
+    /// Initializes a new lazy record from the given record ciphertext and view key.
+    pub fn new(ciphertext: Record<N, Ciphertext<N>>, view_key: &ViewKey<N>) -> Self {
+        // Compute the record view key.
+        let record_view_key = (ciphertext.nonce * **view_key).to_x_coordinate();
+        Self { ciphertext, record_view_key, owner: OnceCell::new(), gates: OnceCell::new(), record: OnceCell::new() }
+    }
+
+    /// Returns the record ciphertext.
+    pub const fn ciphertext(&self) -> &Record<N, Ciphertext<N>> {
+        &self.ciphertext
+    }
+
+    /// Returns the decrypted owner, decrypting only the owner on first access.
+    pub fn owner(&self) -> Result<&Owner<N, Plaintext<N>>> {
+        if let Some(record) = self.record.get() {
+            return Ok(record.owner());
+        }
+        self.owner.get_or_try_init(|| {
+            let randomizers = self.randomizers(self.ciphertext.owner.is_private() as u16);
+            self.ciphertext.owner.decrypt_with_randomizer(&randomizers)
+        })
+    }
+
+    /// Returns the decrypted gates, decrypting only the gates on first access.
+    pub fn gates(&self) -> Result<&Balance<N, Plaintext<N>>> {
+        if let Some(record) = self.record.get() {
+            return Ok(record.gates());
+        }
+        self.gates.get_or_try_init(|| {
+            // The gates randomizer follows the owner randomizer, if the owner is private.
+            let index = self.ciphertext.owner.is_private() as u16;
+            let randomizers = self.randomizers(index + self.ciphertext.gates.is_private() as u16);
+            self.ciphertext.gates.decrypt_with_randomizer(&randomizers[index as usize..])
+        })
+    }
+
+    /// Returns the decrypted record, decrypting the full record on first access.
+    pub fn force(&self) -> Result<&Record<N, Plaintext<N>>> {
+        self.record.get_or_try_init(|| self.ciphertext.decrypt_symmetric(&self.record_view_key))
+    }
+
+    /// Returns the first `num_randomizers` randomizers of the record.
+    fn randomizers(&self, num_randomizers: u16) -> Vec<Field<N>> {
+        match num_randomizers {
+            0 => Vec::new(),
+            _ => N::hash_many_psd8(&[N::encryption_domain(), self.record_view_key], num_randomizers),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_lazy_record(
+        view_key: &ViewKey<CurrentNetwork>,
+        owner: Owner<CurrentNetwork, Plaintext<CurrentNetwork>>,
+        gates: Balance<CurrentNetwork, Plaintext<CurrentNetwork>>,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        // Prepare the record.
+        let randomizer = Scalar::rand(rng);
+        let data = IndexMap::from_iter(vec![(
+            Identifier::from_str("a")?,
+            Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng)))),
+        )]);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext_with_randomizer(
+            owner,
+            gates,
+            data,
+            &randomizer,
+        )?;
+        let ciphertext = record.encrypt(randomizer)?;
+
+        // Ensure the owner and gates decrypt individually.
+        let lazy = LazyRecord::new(ciphertext.clone(), view_key);
+        assert_eq!(record.gates(), lazy.gates()?);
+        assert_eq!(record.owner(), lazy.owner()?);
+        // Ensure the full record decrypts.
+        assert_eq!(&record, lazy.force()?);
+
+        // Ensure the owner and gates are served from the full record, once it is decrypted.
+        let lazy = LazyRecord::new(ciphertext, view_key);
+        assert_eq!(&record, lazy.force()?);
+        assert_eq!(record.owner(), lazy.owner()?);
+        assert_eq!(record.gates(), lazy.gates()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_record() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a view key and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            let public_owner = Owner::Public(address);
+            let private_owner = Owner::Private(Plaintext::from(Literal::Address(address)));
+            let public_gates = Balance::Public(U64::new(u64::rand(&mut rng) >> 12));
+            let private_gates = Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(&mut rng) >> 12))));
+
+            check_lazy_record(&view_key, public_owner.clone(), public_gates.clone(), &mut rng)?;
+            check_lazy_record(&view_key, private_owner.clone(), public_gates, &mut rng)?;
+            check_lazy_record(&view_key, public_owner, private_gates.clone(), &mut rng)?;
+            check_lazy_record(&view_key, private_owner, private_gates, &mut rng)?;
+        }
+        Ok(())
+    }
+}
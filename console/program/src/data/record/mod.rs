@@ -20,18 +20,34 @@ pub use entry::Entry;
 mod helpers;
 pub use helpers::{Balance, Owner};
 
+mod diff;
+pub use diff::RecordDiff;
+
+mod lazy;
+pub use lazy::LazyRecord;
+
+mod scanner;
+pub use scanner::RecordScanner;
+
+mod approx_memory_size;
 mod bytes;
+mod count_distinct_owners;
 mod decrypt;
 mod encrypt;
 mod equal;
 mod find;
 mod is_owner;
+#[cfg(feature = "schemars")]
+mod json_schema;
+mod nonce;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
 mod serial_number;
 mod serialize;
 mod tag;
+#[cfg(feature = "test-vectors")]
+mod test_vector;
 mod to_bits;
 mod to_commitment;
 mod to_fields;
@@ -73,6 +89,18 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         Ok(Record { owner, gates, data, nonce })
     }
 
+    /// Initializes a new record plaintext, whose nonce is derived from the given randomizer.
+    ///
+    /// The same randomizer must be passed to `Record::encrypt` for the owner to decrypt the record.
+    pub fn from_plaintext_with_randomizer(
+        owner: Owner<N, Plaintext<N>>,
+        gates: Balance<N, Plaintext<N>>,
+        data: IndexMap<Identifier<N>, Entry<N, Plaintext<N>>>,
+        randomizer: &Scalar<N>,
+    ) -> Result<Record<N, Plaintext<N>>> {
+        Self::from_plaintext(owner, gates, data, N::g_scalar_multiply(randomizer))
+    }
+
     /// Initializes a new record ciphertext.
     pub fn from_ciphertext(
         owner: Owner<N, Ciphertext<N>>,
@@ -90,6 +118,51 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
     }
 }
 
+impl<N: Network, Private: Visibility> TryFrom<&[u8]> for Record<N, Private> {
+    type Error = Error;
+
+    /// Initializes a record from its little-endian bytes.
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_le(bytes)
+    }
+}
+
+impl<N: Network, Private: Visibility> TryFrom<String> for Record<N, Private>
+where
+    Self: FromStr<Err = Error>,
+{
+    type Error = Error;
+
+    /// Initializes a record from a string.
+    fn try_from(record: String) -> Result<Self> {
+        Self::from_str(&record)
+    }
+}
+
+impl<N: Network, Private: Visibility> TryFrom<&String> for Record<N, Private>
+where
+    Self: FromStr<Err = Error>,
+{
+    type Error = Error;
+
+    /// Initializes a record from a string.
+    fn try_from(record: &String) -> Result<Self> {
+        Self::from_str(record)
+    }
+}
+
+impl<N: Network, Private: Visibility> TryFrom<&str> for Record<N, Private>
+where
+    Self: FromStr<Err = Error>,
+{
+    type Error = Error;
+
+    /// Initializes a record from a string.
+    fn try_from(record: &str) -> Result<Self> {
+        Self::from_str(record)
+    }
+}
+
 impl<N: Network, Private: Visibility> Record<N, Private> {
     /// Returns the owner of the program record.
     pub const fn owner(&self) -> &Owner<N, Private> {
@@ -133,3 +206,56 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         self.nonce
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_plaintext_with_randomizer() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a view key and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Prepare the record from a known randomizer.
+            let randomizer = Scalar::rand(&mut rng);
+            let owner = Owner::Private(Plaintext::from(Literal::Address(address)));
+            let gates = Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(&mut rng) >> 12))));
+            let data = IndexMap::from_iter(vec![(
+                Identifier::from_str("a")?,
+                Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+            )]);
+            let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext_with_randomizer(
+                owner.clone(),
+                gates.clone(),
+                data.clone(),
+                &randomizer,
+            )?;
+
+            // Ensure the construction is deterministic.
+            let candidate = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext_with_randomizer(
+                owner,
+                gates,
+                data,
+                &randomizer,
+            )?;
+            assert_eq!(record, candidate);
+            assert_eq!(record.nonce(), &CurrentNetwork::g_scalar_multiply(&randomizer));
+
+            // Ensure the record encrypts deterministically, and decrypts under the owner's view key.
+            let ciphertext = record.encrypt(randomizer)?;
+            assert_eq!(ciphertext, candidate.encrypt(randomizer)?);
+            assert_eq!(record, ciphertext.decrypt(&view_key)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, Private: Visibility> Record<N, Private> {
+    /// Returns `true` if `self` and `other` have the same nonce.
+    /// As the nonce is derived from the randomizer, this indicates a reused randomizer.
+    pub fn has_same_nonce(&self, other: &Self) -> bool {
+        self.nonce == other.nonce
+    }
+
+    /// Returns the index pairs `(i, j)`, with `i < j`, of the records that have the same nonce.
+    pub fn find_duplicate_nonces(records: &[Self]) -> Vec<(usize, usize)> {
+        // Group the record indices by nonce.
+        let mut indices = IndexMap::<_, Vec<usize>>::with_capacity(records.len());
+        for (index, record) in records.iter().enumerate() {
+            indices.entry(record.nonce).or_default().push(index);
+        }
+        // Return each pair of indices that share a nonce.
+        indices
+            .values()
+            .filter(|indices| indices.len() > 1)
+            .flat_map(|indices| indices.iter().copied().tuple_combinations())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_record(gates: u64, nonce: &str) -> Result<Record<CurrentNetwork, Plaintext<CurrentNetwork>>> {
+        Record::from_str(&format!(
+            "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: {gates}u64.private, _nonce: {nonce}.public }}"
+        ))
+    }
+
+    #[test]
+    fn test_find_duplicate_nonces() -> Result<()> {
+        let generator = CurrentNetwork::g_scalar_multiply(&Scalar::one()).to_string();
+
+        let records = vec![
+            sample_record(1, "0group")?,
+            sample_record(2, &generator)?,
+            sample_record(3, "0group")?,
+            sample_record(4, "0group")?,
+            sample_record(5, &generator)?,
+        ];
+        assert!(records[0].has_same_nonce(&records[2]));
+        assert!(!records[0].has_same_nonce(&records[1]));
+
+        assert_eq!(Record::find_duplicate_nonces(&records), vec![(0, 2), (0, 3), (2, 3), (1, 4)]);
+        assert!(Record::find_duplicate_nonces(&records[..2]).is_empty());
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::find_duplicate_nonces(&[]).is_empty());
+        Ok(())
+    }
+}
@@ -264,6 +264,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_from_string() -> Result<()> {
+        let given = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 99u64.public, _nonce: 0group.public }";
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(given)?;
+        assert_eq!(expected, Record::try_from(given)?);
+        assert_eq!(expected, Record::try_from(&given.to_string())?);
+        assert_eq!(expected, Record::try_from(given.to_string())?);
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::try_from("{ gates: 99u64.public }").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() -> Result<()> {
         // Missing owner.
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A helper for scanning a stream of record ciphertexts for the records owned by a view key.
+#[derive(Clone)]
+pub struct RecordScanner<N: Network> {
+    /// The view key of the owner.
+    view_key: ViewKey<N>,
+    /// The x-coordinate of the address corresponding to the view key.
+    address_x_coordinate: Field<N>,
+}
+
+impl<N: Network> RecordScanner<N> {
+    /// Initializes a new record scanner for the given view key.
+    pub fn new(view_key: ViewKey<N>) -> Self {
+        // Compute the address x-coordinate once, so it can be reused for every ciphertext.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+        Self { view_key, address_x_coordinate }
+    }
+
+    /// Returns the view key of the scanner.
+    pub const fn view_key(&self) -> &ViewKey<N> {
+        &self.view_key
+    }
+
+    /// Lazily scans the given record ciphertexts, yielding only the records that are
+    /// owned by the view key and that decrypt successfully.
+    pub fn scan<'a, I>(&'a self, ciphertexts: I) -> impl Iterator<Item = Record<N, Plaintext<N>>> + 'a
+    where
+        I: IntoIterator<Item = Record<N, Ciphertext<N>>>,
+        I::IntoIter: 'a,
+    {
+        ciphertexts.into_iter().filter_map(move |ciphertext| {
+            // Skip the record if it is not owned by the view key.
+            match ciphertext.is_owner_with_address_x_coordinate(&self.view_key, &self.address_x_coordinate) {
+                true => ciphertext.decrypt(&self.view_key).ok(),
+                false => None,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    type RecordPlaintext = Record<CurrentNetwork, Plaintext<CurrentNetwork>>;
+    type RecordCiphertext = Record<CurrentNetwork, Ciphertext<CurrentNetwork>>;
+
+    fn sample_ciphertext(
+        owner: Address<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Result<(RecordPlaintext, RecordCiphertext)> {
+        let randomizer = Scalar::rand(rng);
+        let record = RecordPlaintext::from_plaintext(
+            Owner::Private(Plaintext::from(Literal::Address(owner))),
+            Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(rng) >> 12)))),
+            IndexMap::from_iter(vec![(
+                Identifier::from_str("a")?,
+                Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng)))),
+            )]),
+            CurrentNetwork::g_scalar_multiply(&randomizer),
+        )?;
+        let ciphertext = record.encrypt(randomizer)?;
+        Ok((record, ciphertext))
+    }
+
+    #[test]
+    fn test_scan() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the view key and address of the scanner.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Sample an unrelated address.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        // Interleave owned and unowned ciphertexts.
+        let mut expected = Vec::with_capacity(ITERATIONS);
+        let mut ciphertexts = Vec::with_capacity(2 * ITERATIONS);
+        for _ in 0..ITERATIONS {
+            let (record, ciphertext) = sample_ciphertext(address, &mut rng)?;
+            expected.push(record);
+            ciphertexts.push(ciphertext);
+            ciphertexts.push(sample_ciphertext(other_address, &mut rng)?.1);
+        }
+
+        // Ensure only the owned records are yielded, in order.
+        let scanner = RecordScanner::new(view_key);
+        let records = scanner.scan(ciphertexts).collect::<Vec<_>>();
+        assert_eq!(expected, records);
+
+        // Ensure an empty stream yields nothing.
+        assert_eq!(0, scanner.scan(core::iter::empty()).count());
+        Ok(())
+    }
+}
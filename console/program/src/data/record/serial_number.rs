@@ -35,3 +35,59 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         N::commit_bhp512(&(N::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
     }
 }
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns `true` if the given serial number is derived from the given private key and the commitment of `self`.
+    ///
+    /// Note that a compute key is insufficient for this check, as the serial number
+    /// is derived from `sk_sig`, which is only available in the private key.
+    pub fn verify_serial_number(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        serial_number: &Field<N>,
+    ) -> Result<bool> {
+        // Compute the record commitment.
+        let commitment = self.to_commitment(program_id, record_name)?;
+        // Recompute the serial number, and compare it to the given serial number.
+        Ok(&Self::serial_number(*private_key, commitment)? == serial_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_serial_number() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let record_name = Identifier::<CurrentNetwork>::from_str("token")?;
+
+        // Prepare the record.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(&private_key)?;
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, gates: 5u64.private, _nonce: 0group.public }}"
+        ))?;
+
+        // Compute the serial number.
+        let commitment = record.to_commitment(&program_id, &record_name)?;
+        let serial_number =
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(private_key, commitment)?;
+        assert!(record.verify_serial_number(&private_key, &program_id, &record_name, &serial_number)?);
+
+        // Ensure a different serial number, private key, or record name fails.
+        assert!(!record.verify_serial_number(&private_key, &program_id, &record_name, &Field::rand(&mut rng))?);
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        assert!(!record.verify_serial_number(&other_private_key, &program_id, &record_name, &serial_number)?);
+        let other_record_name = Identifier::<CurrentNetwork>::from_str("other")?;
+        assert!(!record.verify_serial_number(&private_key, &program_id, &other_record_name, &serial_number)?);
+        Ok(())
+    }
+}
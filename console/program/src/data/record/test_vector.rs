@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns a record plaintext that is deterministically sampled from the given seed,
+    /// along with its JSON and byte encodings, for use as a known-answer test.
+    pub fn test_vector(seed: u64) -> Result<(Self, String, Vec<u8>)> {
+        let mut rng = TestRng::fixed(seed);
+
+        // Sample the owner.
+        let private_key = PrivateKey::<N>::new(&mut rng)?;
+        let owner = Owner::Private(Plaintext::from(Literal::Address(Address::try_from(&private_key)?)));
+        // Sample the gates.
+        let gates = Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(&mut rng) >> 12))));
+        // Sample the data.
+        let data = IndexMap::from_iter([
+            (Identifier::from_str("a")?, Entry::Private(Plaintext::from(Literal::Field(Uniform::rand(&mut rng))))),
+            (Identifier::from_str("b")?, Entry::Public(Plaintext::from(Literal::Boolean(Uniform::rand(&mut rng))))),
+            (Identifier::from_str("c")?, Entry::Constant(Plaintext::from(Literal::U32(Uniform::rand(&mut rng))))),
+        ]);
+        // Sample the nonce.
+        let nonce = N::g_scalar_multiply(&Uniform::rand(&mut rng));
+
+        // Construct the record.
+        let record = Self::from_plaintext(owner, gates, data, nonce)?;
+        // Encode the record.
+        let json = serde_json::to_string(&record)?;
+        let bytes = record.to_bytes_le()?;
+        Ok((record, json, bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_test_vector() -> Result<()> {
+        for seed in 0..10 {
+            let (record, json, bytes) = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::test_vector(seed)?;
+
+            // Ensure the test vector is deterministic.
+            let (expected_record, expected_json, expected_bytes) = Record::test_vector(seed)?;
+            assert_eq!(expected_record, record);
+            assert_eq!(expected_json, json);
+            assert_eq!(expected_bytes, bytes);
+
+            // Ensure the encodings decode to the record.
+            assert_eq!(record, serde_json::from_str(&json)?);
+            assert_eq!(record, Record::read_le(&bytes[..])?);
+
+            // Ensure distinct seeds produce distinct records.
+            let (other_record, _, _) = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::test_vector(seed + 100)?;
+            assert_ne!(record, other_record);
+        }
+        Ok(())
+    }
+}
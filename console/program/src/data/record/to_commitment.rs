@@ -16,15 +16,45 @@
 
 use super::*;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 impl<N: Network> Record<N, Plaintext<N>> {
     /// Returns the record commitment.
     pub fn to_commitment(&self, program_id: &ProgramID<N>, record_name: &Identifier<N>) -> Result<Field<N>> {
+        // Compute the BHP hash of the program record.
+        N::hash_bhp1024(&self.to_commitment_preimage(program_id, record_name))
+    }
+
+    /// Returns the input to the record commitment, as `(program_id || record_name || record)` in little-endian bits.
+    /// The circuit record commitment must be computed over the same layout.
+    pub fn to_commitment_preimage(&self, program_id: &ProgramID<N>, record_name: &Identifier<N>) -> Vec<bool> {
         // Construct the input as `(program_id || record_name || record)`.
         let mut input = program_id.to_bits_le();
         input.extend(record_name.to_bits_le());
         input.extend(self.to_bits_le());
-        // Compute the BHP hash of the program record.
-        N::hash_bhp1024(&input)
+        input
+    }
+
+    /// Returns `true` if the record commitment matches the given commitment.
+    pub fn commitment_matches(
+        &self,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        expected: &Field<N>,
+    ) -> bool {
+        matches!(self.to_commitment(program_id, record_name), Ok(candidate) if candidate == *expected)
+    }
+
+    /// Returns `true` for each `(program_id, record_name, record, commitment)` tuple
+    /// whose record commitment matches the given commitment, in the given order.
+    #[allow(clippy::type_complexity)]
+    pub fn batch_verify_commitments(records: &[(ProgramID<N>, Identifier<N>, Self, Field<N>)]) -> Vec<bool> {
+        cfg_iter!(records)
+            .map(|(program_id, record_name, record, commitment)| {
+                record.commitment_matches(program_id, record_name, commitment)
+            })
+            .collect()
     }
 }
 
@@ -34,3 +64,63 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         bail!("Illegal operation: Record::to_commitment() cannot be invoked on the `Ciphertext` variant.")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_commitment_matches() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let record_name = Identifier::<CurrentNetwork>::from_str("token")?;
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, _nonce: 0group.public }",
+        )?;
+        let commitment = record.to_commitment(&program_id, &record_name)?;
+
+        assert!(record.commitment_matches(&program_id, &record_name, &commitment));
+        assert!(!record.commitment_matches(&program_id, &record_name, &Field::rand(&mut rng)));
+        // Ensure the commitment is bound to the record name.
+        let other_name = Identifier::<CurrentNetwork>::from_str("credits")?;
+        assert!(!record.commitment_matches(&program_id, &other_name, &commitment));
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_verify_commitments() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let record_name = Identifier::<CurrentNetwork>::from_str("token")?;
+
+        // Prepare the records and their commitments.
+        let mut records = Vec::new();
+        for i in 0..10u64 {
+            let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+                "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: {i}u64.private, _nonce: 0group.public }}"
+            ))?;
+            let commitment = record.to_commitment(&program_id, &record_name)?;
+            records.push((program_id, record_name, record, commitment));
+        }
+        assert!(Record::batch_verify_commitments(&records).into_iter().all(|is_valid| is_valid));
+
+        // Tamper with one of the commitments.
+        records[3].3 = Field::rand(&mut rng);
+        let expected = (0..10).map(|i| i != 3).collect::<Vec<_>>();
+        assert_eq!(Record::batch_verify_commitments(&records), expected);
+
+        // Ensure the commitment is the hash of the commitment preimage.
+        let (_, _, record, commitment) = &records[0];
+        let preimage = record.to_commitment_preimage(&program_id, &record_name);
+        assert_eq!(*commitment, CurrentNetwork::hash_bhp1024(&preimage)?);
+
+        // Ensure an empty batch is handled.
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::batch_verify_commitments(&[]).is_empty());
+        Ok(())
+    }
+}
@@ -68,6 +68,24 @@ pub trait ConstraintSystem<F: Field>: Sized {
         LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
         LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>;
 
+    /// Enforce that `a` = `b`, as the constraint `(a - b)` * `1` = `0`.
+    fn enforce_equal<A, AR>(&mut self, annotation: A, a: Variable, b: Variable)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.enforce(annotation, |lc| lc + a - b, |lc| lc + Self::one(), |lc| lc);
+    }
+
+    /// Enforce that `a` = `0`, as the constraint `a` * `1` = `0`.
+    fn enforce_zero<A, AR>(&mut self, annotation: A, a: LinearCombination<F>)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.enforce(annotation, |lc| lc + &a, |lc| lc + Self::one(), |lc| lc);
+    }
+
     /// Create a new (sub)namespace and enter into it. Not intended
     /// for downstream use; use `namespace` instead.
     fn push_namespace<NR, N>(&mut self, name_fn: N)
@@ -188,3 +206,41 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for &mut CS {
         (**self).is_in_setup_mode()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestConstraintSystem;
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_enforce_equal() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let a = cs.alloc(|| "a", || Ok(Fr::from(5u64))).unwrap();
+        let b = cs.alloc(|| "b", || Ok(Fr::from(5u64))).unwrap();
+        let c = cs.alloc(|| "c", || Ok(Fr::from(6u64))).unwrap();
+
+        cs.enforce_equal(|| "a == b", a, b);
+        assert!(cs.is_satisfied());
+        assert_eq!(cs.num_constraints(), 1);
+
+        cs.enforce_equal(|| "a == c", a, c);
+        assert!(!cs.is_satisfied());
+        assert_eq!(cs.which_is_unsatisfied().unwrap(), "a == c");
+    }
+
+    #[test]
+    fn test_enforce_zero() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let a = cs.alloc(|| "a", || Ok(Fr::from(5u64))).unwrap();
+        let b = cs.alloc(|| "b", || Ok(Fr::from(5u64))).unwrap();
+
+        cs.enforce_zero(|| "a - b == 0", LinearCombination::from(a) - b);
+        assert!(cs.is_satisfied());
+        assert_eq!(cs.num_constraints(), 1);
+
+        cs.enforce_zero(|| "a == 0", LinearCombination::from(a));
+        assert!(!cs.is_satisfied());
+        assert_eq!(cs.which_is_unsatisfied().unwrap(), "a == 0");
+    }
+}
@@ -49,6 +49,12 @@ pub enum SynthesisError {
     /// During CRS generation, we observed an unconstrained auxiliary variable
     #[error("Auxiliary variable was unconstrained")]
     UnconstrainedVariable,
+    /// During synthesis, the number of variables exceeded `usize::MAX`.
+    #[error("The number of variables exceeds the maximum supported")]
+    TooManyVariables,
+    /// During constraint checking, the witness did not satisfy the constraint at the given index.
+    #[error("Constraint {index} ('{name}') is not satisfied: ({a}) * ({b}) != ({c})")]
+    UnsatisfiedConstraint { index: usize, name: String, a: String, b: String, c: String },
 }
 
 impl From<std::io::Error> for SynthesisError {
@@ -47,6 +47,11 @@ pub use test_constraint_system::{Fr, TestConstraintSystem};
 mod test_constraint_checker;
 pub use test_constraint_checker::TestConstraintChecker;
 
+#[cfg(feature = "tracing")]
+mod tracing_constraint_system;
+#[cfg(feature = "tracing")]
+pub use tracing_constraint_system::TracingConstraintSystem;
+
 use snarkvm_utilities::serialize::*;
 
 use std::cmp::Ordering;
@@ -78,6 +78,12 @@ impl<F: Field> LinearCombination<F> {
         });
     }
 
+    /// Returns the number of `(variable, coefficient)` terms in `self`.
+    #[inline]
+    pub fn num_terms(&self) -> usize {
+        self.0.len()
+    }
+
     /// Get the location of a variable in `self`.
     #[inline]
     pub fn get_var_loc(&self, search_var: &Variable) -> Result<usize, usize> {
@@ -444,4 +450,19 @@ mod tests {
         }
         assert_eq!(combo.0.len(), 1);
     }
+
+    #[test]
+    fn linear_combination_num_terms() {
+        let mut combo = LinearCombination::<Fr>::zero();
+        assert_eq!(combo.num_terms(), 0);
+
+        for i in 0..10 {
+            combo += (1u64.into(), Variable::new_unchecked(Index::Private(i)));
+        }
+        assert_eq!(combo.num_terms(), 10);
+
+        // Adding to an existing variable does not introduce a new term.
+        combo += (1u64.into(), Variable::new_unchecked(Index::Private(0)));
+        assert_eq!(combo.num_terms(), 10);
+    }
 }
@@ -260,6 +260,26 @@ impl<F: Field> TestConstraintSystem<F> {
         (non_zero_a, non_zero_b, non_zero_c)
     }
 
+    /// Returns the maximum and the average number of terms in the linear combinations
+    /// of all enforced constraints, which helps locate gadgets with un-simplified combinations.
+    #[inline]
+    pub fn linear_combination_sizes(&self) -> (usize, f64) {
+        let mut max_terms = 0;
+        let mut total_terms = 0;
+        let mut num_linear_combinations = 0;
+        for TestConstraint { a, b, c, .. } in self.constraints.iter() {
+            for lc in [a, b, c] {
+                max_terms = max_terms.max(lc.len());
+                total_terms += lc.len();
+                num_linear_combinations += 1;
+            }
+        }
+        match num_linear_combinations {
+            0 => (0, 0.0),
+            _ => (max_terms, total_terms as f64 / num_linear_combinations as f64),
+        }
+    }
+
     #[inline]
     pub fn num_constraints(&self) -> usize {
         self.constraints.len()
@@ -505,3 +525,29 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_linear_combination_sizes() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        assert_eq!(cs.linear_combination_sizes(), (0, 0.0));
+
+        let a = cs.alloc(|| "a", || Ok(Fr::one())).unwrap();
+        let b = cs.alloc(|| "b", || Ok(Fr::one())).unwrap();
+        let c = cs.alloc(|| "c", || Ok(Fr::one())).unwrap();
+        let one = TestConstraintSystem::<Fr>::one();
+
+        // Enforce linear combinations of 1, 1, and 1 terms.
+        cs.enforce(|| "a * b = c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        // Enforce linear combinations of 3, 1, and 3 terms.
+        cs.enforce(|| "(a + b + c) * 1 = a + b + c", |lc| lc + a + b + c, |lc| lc + one, |lc| lc + a + b + c);
+        // Enforce empty linear combinations.
+        cs.enforce(|| "0 * 0 = 0", |lc| lc, |lc| lc, |lc| lc);
+
+        assert_eq!(cs.linear_combination_sizes(), (3, 10.0 / 9.0));
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintSystem, LinearCombination, Variable};
+use snarkvm_fields::Field;
+
+use std::marker::PhantomData;
+
+/// A constraint system which forwards every call to an inner constraint system,
+/// while logging each allocation and constraint with its full namespace path.
+/// As the namespaces are tracked here, the labels are recorded even if the inner
+/// constraint system ignores them, as the Marlin prover does.
+pub struct TracingConstraintSystem<F: Field, CS: ConstraintSystem<F>> {
+    inner: CS,
+    namespaces: Vec<String>,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> TracingConstraintSystem<F, CS> {
+    /// Initializes a new tracing constraint system over the given constraint system.
+    pub fn new(inner: CS) -> Self {
+        Self { inner, namespaces: Vec::new(), _field: PhantomData }
+    }
+
+    /// Returns a reference to the inner constraint system.
+    pub fn inner(&self) -> &CS {
+        &self.inner
+    }
+
+    /// Returns the inner constraint system.
+    pub fn into_inner(self) -> CS {
+        self.inner
+    }
+
+    /// Returns the path of the given annotation in the current namespace.
+    fn path(&self, annotation: &str) -> String {
+        self.namespaces.iter().map(String::as_str).chain(std::iter::once(annotation)).collect::<Vec<_>>().join("/")
+    }
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for TracingConstraintSystem<F, CS> {
+    type Root = Self;
+
+    #[inline]
+    fn one() -> Variable {
+        CS::one()
+    }
+
+    #[inline]
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let annotation = annotation();
+        let variable = self.inner.alloc(|| annotation.as_ref(), f)?;
+        tracing::trace!("alloc {} -> {:?}", self.path(annotation.as_ref()), variable.get_unchecked());
+        Ok(variable)
+    }
+
+    #[inline]
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let annotation = annotation();
+        let variable = self.inner.alloc_input(|| annotation.as_ref(), f)?;
+        tracing::trace!("alloc_input {} -> {:?}", self.path(annotation.as_ref()), variable.get_unchecked());
+        Ok(variable)
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let annotation = annotation();
+        let index = self.inner.num_constraints();
+        self.inner.enforce(|| annotation.as_ref(), a, b, c);
+        tracing::trace!("enforce {} -> constraint {index}", self.path(annotation.as_ref()));
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        let name = name_fn().as_ref().to_string();
+        self.inner.get_root().push_namespace(|| name.as_str());
+        self.namespaces.push(name);
+    }
+
+    fn pop_namespace(&mut self) {
+        self.inner.get_root().pop_namespace();
+        self.namespaces.pop();
+    }
+
+    #[inline]
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    #[inline]
+    fn num_constraints(&self) -> usize {
+        self.inner.num_constraints()
+    }
+
+    #[inline]
+    fn num_public_variables(&self) -> usize {
+        self.inner.num_public_variables()
+    }
+
+    #[inline]
+    fn num_private_variables(&self) -> usize {
+        self.inner.num_private_variables()
+    }
+
+    #[inline]
+    fn is_in_setup_mode(&self) -> bool {
+        self.inner.is_in_setup_mode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestConstraintSystem;
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_tracing_constraint_system() {
+        let mut cs = TracingConstraintSystem::new(TestConstraintSystem::<Fr>::new());
+        let a = cs.alloc_input(|| "a", || Ok(Fr::from(2u64))).unwrap();
+        {
+            let mut cs = cs.ns(|| "gadget");
+            let b = cs.alloc(|| "b", || Ok(Fr::from(3u64))).unwrap();
+            let c = cs.alloc(|| "c", || Ok(Fr::from(6u64))).unwrap();
+            cs.enforce(|| "a * b = c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        }
+        assert!(cs.namespaces.is_empty());
+        assert_eq!(cs.path("d"), "d");
+
+        // Ensure the calls are forwarded to the inner constraint system, with their namespaces.
+        assert_eq!(cs.num_public_variables(), 2);
+        assert_eq!(cs.num_private_variables(), 2);
+        assert_eq!(cs.num_constraints(), 1);
+
+        let mut inner = cs.into_inner();
+        assert!(inner.is_satisfied());
+        assert_eq!(inner.get_constraint_path(0), "gadget/a * b = c");
+        assert_eq!(inner.get("gadget/c"), Fr::from(6u64));
+    }
+}
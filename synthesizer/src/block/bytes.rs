@@ -115,4 +115,54 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_rejects_mismatched_transactions_root() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Load the genesis block.
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+        let header = *genesis_block.header();
+        assert!(genesis_block.coinbase().is_none());
+
+        // Sample a block signer.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+        // Serializes the genesis block under the given header, signed by the sampled signer.
+        let mut to_bytes = |header: Header<CurrentNetwork>| -> Result<Vec<u8>> {
+            let previous_hash = genesis_block.previous_hash();
+            let block_hash =
+                CurrentNetwork::hash_bhp1024(&[previous_hash.to_bits_le(), header.to_root()?.to_bits_le()].concat())?;
+            let signature = private_key.sign(&[block_hash], &mut rng)?;
+
+            let mut bytes = 0u16.to_bytes_le()?;
+            bytes.extend(<CurrentNetwork as Network>::BlockHash::from(block_hash).to_bytes_le()?);
+            bytes.extend(previous_hash.to_bytes_le()?);
+            bytes.extend(header.to_bytes_le()?);
+            bytes.extend(genesis_block.transactions().to_bytes_le()?);
+            bytes.extend(0u8.to_bytes_le()?);
+            bytes.extend(signature.to_bytes_le()?);
+            Ok(bytes)
+        };
+
+        // Ensure the re-signed genesis block is valid.
+        let expected_bytes = to_bytes(header)?;
+        assert_eq!(header, *Block::<CurrentNetwork>::read_le(&expected_bytes[..])?.header());
+
+        // Flip a bit of the transactions root, and re-sign the block.
+        let mut transactions_root = header.transactions_root().to_bytes_le()?;
+        transactions_root[0] ^= 1;
+        let tampered_header = Header::from(
+            header.previous_state_root(),
+            Field::read_le(&transactions_root[..])?,
+            header.coinbase_accumulator_point(),
+            *header.metadata(),
+        )?;
+        let candidate_bytes = to_bytes(tampered_header)?;
+
+        // Ensure the block is rejected, even though its signature is valid.
+        let error = Block::<CurrentNetwork>::read_le(&candidate_bytes[..]).unwrap_err();
+        assert!(error.to_string().contains("transactions root"), "{error}");
+        Ok(())
+    }
 }
@@ -89,6 +89,11 @@ impl<N: Network> Block<N> {
     ) -> Result<Self> {
         // Ensure the block is not empty.
         ensure!(!transactions.is_empty(), "Cannot create a block with zero transactions.");
+        // Ensure the transactions root matches, as the block hash does not commit to the transactions directly.
+        ensure!(
+            header.transactions_root() == transactions.to_root()?,
+            "The transactions root in the block header does not correspond to the given transactions"
+        );
         // Compute the block hash.
         let block_hash = N::hash_bhp1024(&[previous_hash.to_bits_le(), header.to_root()?.to_bits_le()].concat())?;
         // Derive the signer address.
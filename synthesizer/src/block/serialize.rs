@@ -125,6 +125,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_serde_json_rejects_mismatched_transactions() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Load the genesis block.
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        // Sample a different block.
+        let other_block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+
+        // Substitute the transactions of the genesis block with those of the other block.
+        let mut candidate = serde_json::to_value(&genesis_block)?;
+        candidate["transactions"] = serde_json::to_value(other_block.transactions())?;
+
+        // Ensure the tampered block fails to deserialize.
+        assert!(serde_json::from_value::<Block<CurrentNetwork>>(candidate).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_genesis_bincode() -> Result<()> {
         // Load the genesis block.
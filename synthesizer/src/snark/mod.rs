@@ -28,7 +28,8 @@ use std::sync::Arc;
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
 
-type Marlin<N> = marlin::MarlinSNARK<<N as Environment>::PairingCurve, FiatShamir<N>, marlin::MarlinHidingMode>;
+type MarlinMode = marlin::MarlinHidingMode;
+type Marlin<N> = marlin::MarlinSNARK<<N as Environment>::PairingCurve, FiatShamir<N>, MarlinMode>;
 
 mod certificate;
 pub use certificate::Certificate;
@@ -37,6 +37,9 @@ impl<N: Network> UniversalSRS<N> {
         #[cfg(feature = "aleo-cli")]
         let timer = std::time::Instant::now();
 
+        // Ensure the circuit fits within the universal SRS, before indexing the circuit.
+        Self::ensure_fits_within(function_name, assignment, self.max_degree())?;
+
         let (proving_key, verifying_key) = Marlin::<N>::circuit_setup(self, assignment)?;
 
         #[cfg(feature = "aleo-cli")]
@@ -46,6 +49,46 @@ impl<N: Network> UniversalSRS<N> {
     }
 }
 
+impl<N: Network> UniversalSRS<N> {
+    /// Returns the minimum SRS degree required to index the given circuit.
+    pub fn circuit_degree(assignment: &circuit::Assignment<N::Field>) -> Result<usize> {
+        let (num_constraints, num_variables, num_non_zero) = Self::circuit_size(assignment);
+        marlin::AHPForR1CS::<N::Field, MarlinMode>::max_degree(num_constraints, num_variables, num_non_zero)
+            .map_err(|error| anyhow!("Failed to compute the degree of the circuit: {error:?}"))
+    }
+
+    /// Returns `true` if the given circuit can be indexed with this universal SRS.
+    pub fn fits_within(&self, assignment: &circuit::Assignment<N::Field>) -> Result<bool> {
+        Ok(Self::circuit_degree(assignment)? <= self.max_degree())
+    }
+
+    /// Returns the number of constraints, variables, and maximum non-zero entries of the given circuit.
+    fn circuit_size(assignment: &circuit::Assignment<N::Field>) -> (usize, usize, usize) {
+        let (num_non_zero_a, num_non_zero_b, num_non_zero_c) = assignment.num_nonzeros();
+        let num_constraints = assignment.num_constraints() as usize;
+        let num_variables = (assignment.num_public() + 1 + assignment.num_private()) as usize;
+        let num_non_zero = num_non_zero_a.max(num_non_zero_b).max(num_non_zero_c) as usize;
+        (num_constraints, num_variables, num_non_zero)
+    }
+
+    /// Ensures the given circuit can be indexed with an SRS of the given maximum degree.
+    fn ensure_fits_within(
+        function_name: &Identifier<N>,
+        assignment: &circuit::Assignment<N::Field>,
+        max_degree: usize,
+    ) -> Result<()> {
+        let (num_constraints, num_variables, num_non_zero) = Self::circuit_size(assignment);
+        let degree = Self::circuit_degree(assignment)
+            .map_err(|error| anyhow!("Failed to compute the degree of '{function_name}': {error}"))?;
+        ensure!(
+            degree <= max_degree,
+            "'{function_name}' requires an SRS of degree at least {degree}, which exceeds the maximum degree {max_degree} \
+             ({num_constraints} constraints, {num_variables} variables, {num_non_zero} non-zero entries)"
+        );
+        Ok(())
+    }
+}
+
 impl<N: Network> FromBytes for UniversalSRS<N> {
     /// Reads the universal SRS from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
@@ -79,3 +122,47 @@ impl<N: Network> Deref for UniversalSRS<N> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{environment::Eject, network::AleoV0, Environment as _, Inject, Mode};
+    use console::{network::Testnet3, types::Field};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Returns the assignment of a toy circuit that enforces `num_constraints` multiplications.
+    fn sample_assignment(num_constraints: usize) -> circuit::Assignment<<CurrentNetwork as Environment>::Field> {
+        let base = circuit::Field::<CurrentAleo>::new(Mode::Private, Field::from_u64(3));
+        let mut accumulator = circuit::Field::<CurrentAleo>::new(Mode::Public, Field::from_u64(1));
+        for _ in 0..num_constraints {
+            accumulator = &accumulator * &base;
+        }
+        assert!(!accumulator.eject_value().is_zero());
+        CurrentAleo::eject_assignment_and_reset()
+    }
+
+    #[test]
+    fn test_circuit_degree() {
+        let small = UniversalSRS::<CurrentNetwork>::circuit_degree(&sample_assignment(10)).unwrap();
+        let large = UniversalSRS::<CurrentNetwork>::circuit_degree(&sample_assignment(1000)).unwrap();
+        assert!(small < large);
+    }
+
+    #[test]
+    fn test_ensure_fits_within() {
+        let function_name = Identifier::<CurrentNetwork>::from_str("toy").unwrap();
+        let assignment = sample_assignment(1000);
+        let degree = UniversalSRS::<CurrentNetwork>::circuit_degree(&assignment).unwrap();
+
+        // Ensure the circuit is accepted by an SRS of exactly the required degree.
+        assert!(UniversalSRS::<CurrentNetwork>::ensure_fits_within(&function_name, &assignment, degree).is_ok());
+
+        // Ensure the circuit is rejected by an SRS that is too small.
+        let error =
+            UniversalSRS::<CurrentNetwork>::ensure_fits_within(&function_name, &assignment, degree - 1).unwrap_err();
+        assert!(error.to_string().contains("exceeds the maximum degree"), "{error}");
+        assert!(error.to_string().contains("'toy'"), "{error}");
+    }
+}